@@ -17,4 +17,13 @@ pub mod const_authority {
     );
 
     pub const VAULT_BUMP: u8 = VAULT_AUTHORITY_AND_BUMP.1;
+
+    const VAULT_SIGNER_SEEDS: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
+
+    /// Signer seeds for the vault authority PDA, always built from the compile-time VAULT_BUMP
+    /// `bump` is the runtime-derived bump (ctx.bumps.vault_authority), checked in debug builds
+    pub fn vault_signer_seeds(bump: u8) -> &'static [&'static [&'static [u8]]] {
+        debug_assert_eq!(bump, VAULT_BUMP, "vault_authority bump does not match VAULT_BUMP");
+        VAULT_SIGNER_SEEDS
+    }
 }
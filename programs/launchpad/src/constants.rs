@@ -7,22 +7,34 @@ pub const USER_DIVIDEND_SEED: &[u8] = b"user_dividend";
 pub const VAULT_AUTHORITY: &[u8] = b"vault_authority";
 pub const TOKEN_VAULT: &[u8] = b"token_vault";
 pub const TOKEN_MINT_SEED: &[u8] = b"token_mint";
+pub const SOL_DIVIDEND_SEED: &[u8] = b"sol_dividend";
+pub const USER_SOL_DIVIDEND_SEED: &[u8] = b"user_sol_dividend";
+pub const CREATOR_FEE_ESCROW_SEED: &[u8] = b"creator_fee_escrow";
 
 // ===== Token Configuration =====
 /// Token decimals (standard SPL token)
 pub const TOKEN_DECIMALS: u8 = 6;
 
+/// Maximum token decimals a launch may choose, matching SOL
+pub const MAX_TOKEN_DECIMALS: u8 = 9;
+
+/// Default supply in whole tokens: 1 billion
+pub const TOTAL_SUPPLY_TOKENS: u64 = 1_000_000_000;
+
 /// Total supply: 1 billion tokens
-pub const TOTAL_SUPPLY: u64 = 1_000_000_000 * 10u64.pow(TOKEN_DECIMALS as u32);
+pub const TOTAL_SUPPLY: u64 = TOTAL_SUPPLY_TOKENS * 10u64.pow(TOKEN_DECIMALS as u32);
+
+/// Fixed-point scale applied to implied token prices
+pub const PRICE_SCALE: u128 = 1_000_000_000;
 
 // ===== Token Allocation =====
-/// Creator allocation: 30%
+/// Default creator allocation: 30%
 pub const CREATOR_ALLOCATION_PERCENT: u8 = 30;
 
-/// Sale allocation: 50%
+/// Default sale allocation: 50%
 pub const SALE_ALLOCATION_PERCENT: u8 = 50;
 
-/// Liquidity allocation: 20%
+/// Default liquidity allocation: 20%
 pub const LIQUIDITY_ALLOCATION_PERCENT: u8 = 20;
 
 // ===== Launch Parameters =====
@@ -32,6 +44,36 @@ pub const DEFAULT_TARGET_SOL: u64 = 100 * anchor_lang::solana_program::native_to
 /// Default launch duration: 12 hours
 pub const DEFAULT_LAUNCH_DURATION: i64 = 12 * 60 * 60;
 
+/// Maximum number of automatic near-miss end time extensions per launch
+pub const MAX_NEAR_MISS_EXTENSIONS: u8 = 3;
+
+/// Maximum length of a single near-miss extension: 24 hours
+pub const MAX_NEAR_MISS_EXTENSION_DURATION: i64 = 24 * 60 * 60;
+
+/// Maximum grace period after end_time before a sub-target launch can fail: 24 hours
+pub const MAX_FINALIZE_GRACE_PERIOD: i64 = 24 * 60 * 60;
+
+/// Maximum delay for staged sensitive config changes: 7 days
+pub const MAX_CONFIG_TIMELOCK: i64 = 7 * 24 * 60 * 60;
+
+/// Grace period after claims open before unclaimed sale tokens can be swept: 90 days
+pub const UNSOLD_TOKEN_SWEEP_DELAY: i64 = 90 * 24 * 60 * 60;
+
+/// Maximum number of pools finalize_launch_batch processes in one transaction
+pub const MAX_FINALIZE_BATCH_SIZE: usize = 10;
+
+/// Maximum number of pools participate_batch contributes to in one transaction
+pub const MAX_PARTICIPATE_BATCH_SIZE: usize = 5;
+
+/// Fixed-point scale of StakingRewardConfig::reward_per_token_stored
+pub const REWARD_PER_TOKEN_PRECISION: u128 = 1_000_000_000_000;
+
+/// Maximum number of pools claim_creator_tokens_batch claims from in one transaction
+pub const MAX_CREATOR_CLAIM_BATCH_SIZE: usize = 5;
+
+/// Maximum unstake grace for clock skew: 5 minutes
+pub const MAX_UNSTAKE_GRACE_SECONDS: i64 = 5 * 60;
+
 // ===== Creator Lock Configuration =====
 /// Default creator lock duration: 30 days (in seconds)
 pub const DEFAULT_CREATOR_LOCK_DURATION: i64 = 30 * 24 * 60 * 60;
@@ -42,12 +84,15 @@ pub const DEFAULT_CREATOR_LINEAR_UNLOCK_DURATION: i64 = 90 * 24 * 60 * 60;
 /// Maximum contribution per user (prevent monopolization)
 pub const MAX_CONTRIBUTION_PER_USER: u64 = 3 * anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
 
+/// Default hard cap as a multiple of target_sol
+pub const DEFAULT_HARD_CAP_MULTIPLIER: u64 = 2;
+
+/// Maximum number of staking lock duration presets
+pub const MAX_LOCK_DURATION_PRESETS: usize = 4;
+
 /// Minimum contribution per user
 pub const MIN_CONTRIBUTION_PER_USER: u64 = anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL / 10; // 0.1 SOL
 
-/// sqrt(100000000000/200000000000000) * 2^64
-pub const SQRT_PRICE: u128 = 412481737123559485;
-
 // ===== Pool Price Range =====
 /// Minimum sqrt price for damm_v2 pool
 pub const MIN_SQRT_PRICE: u128 = 4295048016;
@@ -61,3 +106,34 @@ pub const FEE_DENOMINATOR: u64 = 1_000_000_000;
 
 /// Max basis point (10000 = 100%)
 pub const MAX_BASIS_POINT: u64 = 10_000;
+
+/// Default maximum number of dividend records a single user can open
+pub const DEFAULT_MAX_DIVIDEND_RECORDS_PER_USER: u32 = 1_000;
+
+/// Default minimum liquidity_sol / raised_sol ratio at migration: 10%
+pub const DEFAULT_MIN_LIQUIDITY_RATIO_BPS: u16 = 1_000;
+
+/// Default Meteora pool base fee used at migration: 1.5%
+pub const DEFAULT_POOL_FEE_BPS: u16 = 150;
+
+/// Maximum Meteora pool base fee a launch may choose: 10%
+pub const MAX_POOL_FEE_BPS: u16 = 1_000;
+
+/// Default share of migrated liquidity permanently locked: 50%
+pub const DEFAULT_PERMANENT_LOCK_BPS: u16 = 5_000;
+
+/// Default creator share of claimed pool fees: 50%
+pub const DEFAULT_FEE_CREATOR_SHARE_BPS: u16 = 5_000;
+
+/// Default swap fee charged on DLMM swaps: 0.05%
+pub const DEFAULT_SWAP_FEE_BPS: u16 = 5;
+
+/// Maximum swap fee: 10%
+pub const MAX_SWAP_FEE_BPS: u16 = 1_000;
+
+/// Default bounds for the Meteora pool base fee: 0.1% - 5%
+pub const DEFAULT_MIN_POOL_FEE_BPS: u16 = 10;
+pub const DEFAULT_MAX_POOL_FEE_BPS: u16 = 500;
+
+/// Default furthest a launch may be scheduled ahead of its creation: 30 days
+pub const DEFAULT_MAX_START_DELAY: i64 = 30 * 24 * 60 * 60;
@@ -9,6 +9,15 @@ pub enum LaunchpadError {
     #[msg("Not the creator of this launch pool")]
     NotCreator,
 
+    #[msg("Explicit confirmation required")]
+    ConfirmationRequired,
+
+    #[msg("No staged config change to apply")]
+    NoPendingConfigChange,
+
+    #[msg("Config minimum bounds exceed their maximums")]
+    InvalidConfigBounds,
+
     // ===== Status Errors =====
     #[msg("Invalid status for this operation")]
     InvalidStatus,
@@ -25,19 +34,46 @@ pub enum LaunchpadError {
     #[msg("Platform is currently paused")]
     PlatformPaused,
 
+    #[msg("Platform must be paused for this operation")]
+    PlatformNotPaused,
+
+    #[msg("Launch pool is paused")]
+    PoolPaused,
+
+    #[msg("Launch pool has reached its hard cap")]
+    HardCapReached,
+
+    #[msg("Launch pool has already been finalized")]
+    AlreadyFinalized,
+
+    #[msg("All launches must be settled, migrated or failed with every refund paid")]
+    LaunchesNotTerminal,
+
     // ===== Time Errors =====
     #[msg("Launch has not started yet")]
     NotStarted,
 
+    #[msg("Launch has already started")]
+    LaunchAlreadyStarted,
+
     #[msg("Launch time window has expired")]
     TimeWindowExpired,
 
     #[msg("Too early to finalize")]
     TooEarlyToFinalize,
 
+    #[msg("Claims are not open yet")]
+    ClaimNotOpen,
+
+    #[msg("Refund delay has not elapsed yet")]
+    RefundNotAvailable,
+
     #[msg("Start time must be in the future")]
     InvalidStartTime,
 
+    #[msg("Config timelock has not elapsed")]
+    ConfigTimelockActive,
+
     // ===== Parameter Errors =====
     #[msg("Invalid target amount")]
     InvalidTargetAmount,
@@ -45,6 +81,15 @@ pub enum LaunchpadError {
     #[msg("Invalid duration")]
     InvalidDuration,
 
+    #[msg("Token symbol is already used by another launch")]
+    DuplicateSymbol,
+
+    #[msg("Launch creator account is required to release the symbol registry")]
+    CreatorAccountRequired,
+
+    #[msg("Invalid near-miss extension parameters")]
+    InvalidExtensionParams,
+
     #[msg("Invalid token allocation")]
     InvalidTokenAllocation,
 
@@ -54,12 +99,36 @@ pub enum LaunchpadError {
     #[msg("Insufficient points balance")]
     InsufficientPoints,
 
+    #[msg("Points used are below the minimum per contribution")]
+    PointsBelowMinimum,
+
+    #[msg("Lifetime contribution cap exceeded")]
+    LifetimeContributionCapExceeded,
+
+    #[msg("Maximum participations per user reached")]
+    TooManyParticipations,
+
+    #[msg("Points balance below the launch's participation minimum")]
+    PointsBalanceTooLow,
+
+    #[msg("Wallet is not on the launch whitelist")]
+    NotWhitelisted,
+
+    #[msg("Launch does not accept contributions without points")]
+    OpenParticipationDisabled,
+
     #[msg("Invalid contribution amount")]
     InvalidContribution,
 
     #[msg("Invalid amount")]
     InvalidAmount,
 
+    #[msg("Invalid batch size")]
+    InvalidBatchSize,
+
+    #[msg("Invalid launch pool account")]
+    InvalidLaunchPool,
+
     // ===== Signature Errors =====
     #[msg("Invalid signature")]
     InvalidSignature,
@@ -67,6 +136,9 @@ pub enum LaunchpadError {
     #[msg("Invalid instruction index")]
     InvalidInstructionIndex,
 
+    #[msg("Signature has expired")]
+    SignatureExpired,
+
     // ===== Math Errors =====
     #[msg("Math overflow")]
     MathOverflow,
@@ -87,9 +159,21 @@ pub enum LaunchpadError {
     #[msg("Insufficient vault balance")]
     InsufficientVaultBalance,
 
+    #[msg("Too many dividend records for this user")]
+    TooManyDividendRecords,
+
     #[msg("Invalid token mint")]
     InvalidTokenMint,
 
+    #[msg("Fee destination account is missing")]
+    MissingFeeAccount,
+
+    #[msg("Metadata accounts are required when creating metadata")]
+    MetadataAccountsRequired,
+
+    #[msg("Launch mint authority has already been revoked")]
+    MintAuthorityRevoked,
+
     #[msg("Invalid launch status")]
     InvalidLaunchStatus,
 
@@ -102,9 +186,21 @@ pub enum LaunchpadError {
     #[msg("Invalid quote vault")]
     InvalidQuoteVault,
 
+    #[msg("Account is not owned by the expected token program")]
+    InvalidTokenProgram,
+
     #[msg("Insufficient liquidity")]
     InsufficientLiquidity,
 
+    #[msg("Excess SOL has been converted to dividends")]
+    ExcessConverted,
+
+    #[msg("Excess SOL is still claimable by participants")]
+    ExcessStillClaimable,
+
+    #[msg("Liquidity SOL is below the minimum ratio of raised SOL")]
+    LiquidityRatioTooLow,
+
     // ===== Staking Errors =====
     #[msg("Invalid stake duration")]
     InvalidStakeDuration,
@@ -121,16 +217,40 @@ pub enum LaunchpadError {
     #[msg("Cannot stake zero tokens")]
     CannotStakeZeroTokens,
 
+    #[msg("Stake amount is below the minimum")]
+    StakeBelowMinimum,
+
+    #[msg("Staking position cannot be transferred to the same wallet")]
+    InvalidStakeTransfer,
+
+    #[msg("Reward vault and reward token account are required to pay out accrued staking rewards")]
+    StakingRewardAccountsRequired,
+
+    #[msg("Staking reward config is required for positions enrolled in rewards")]
+    StakingRewardConfigRequired,
+
     #[msg("Type conversion failed")]
     TypeCastFailed,
 
     #[msg("Invalid lb_pair address")]
     InvalidLbPair,
 
+    #[msg("Fee account does not match the configured swap fee account")]
+    InvalidFeeAccount,
+
+    #[msg("Swap output is below the minimum amount out")]
+    SlippageExceeded,
+
     // ===== Position Errors =====
     #[msg("Invalid position account")]
     InvalidPosition,
 
+    #[msg("Position still has unclaimed rewards")]
+    PositionNotSettled,
+
     #[msg("Invalid position NFT account")]
     InvalidPositionNftAccount,
+
+    #[msg("Invalid Meteora pool config")]
+    InvalidPoolConfig,
 }
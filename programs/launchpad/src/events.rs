@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 
+use crate::instructions::UpdateConfigParams;
+
 // =============================================================================
 // LAUNCH POOL LIFECYCLE EVENTS
 // =============================================================================
@@ -52,6 +54,10 @@ pub struct ParticipationEvent {
     pub is_first_participation: bool,
     /// Current participant count
     pub participants_count: u32,
+    /// SOL still needed to reach the target after this contribution
+    pub remaining_to_target: u64,
+    /// Seconds until the launch window closes, 0 once it has ended
+    pub seconds_remaining: i64,
     /// Participation timestamp
     pub timestamp: i64,
 }
@@ -98,6 +104,167 @@ pub struct LaunchFinalized {
     pub timestamp: i64,
 }
 
+/// Event emitted when a user's consumed points are reset to the backend's authoritative value
+#[event]
+pub struct PointsReconciled {
+    /// User whose points were reconciled
+    pub user: Pubkey,
+    /// Points consumed before reconciliation
+    pub previous_points_consumed: u64,
+    /// Points consumed after reconciliation
+    pub points_consumed: u64,
+    /// Nonce the signature was bound to
+    pub nonce: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a contribution is reduced to the user's remaining per-user headroom
+#[event]
+pub struct ContributionClamped {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// User who participated
+    pub user: Pubkey,
+    /// SOL implied by the signed points
+    pub requested_sol: u64,
+    /// SOL actually contributed
+    pub accepted_sol: u64,
+    /// Points requested
+    pub requested_points: u64,
+    /// Points charged for the accepted SOL
+    pub points_charged: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted on demand with a pool's current fundraising progress
+#[event]
+pub struct PoolSnapshot {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Current status
+    pub status: u8, // LaunchStatus as u8
+    /// Total amount raised so far
+    pub raised_sol: u64,
+    /// Current participant count
+    pub participants_count: u32,
+    /// Snapshot timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted on demand with a pool's implied token price
+#[event]
+pub struct ImpliedPrice {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Current status
+    pub status: u8, // LaunchStatus as u8
+    /// Lamports per whole token, multiplied by PRICE_SCALE
+    pub price_scaled: u128,
+    /// Fixed-point scale of price_scaled
+    pub price_scale: u128,
+    /// SOL side used for the price
+    pub quote_amount: u64,
+    /// Token side used for the price
+    pub token_amount: u64,
+    /// True if projected from the target, false if from the actual liquidity deposit
+    pub is_projected: bool,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted on demand with a pool's remaining capacity before its target
+#[event]
+pub struct RemainingCapacity {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// SOL still needed to reach the target
+    pub remaining_sol: u64,
+    /// Estimated sale tokens the remaining SOL buys at the target price
+    pub remaining_tokens: u64,
+    /// Total amount raised so far
+    pub raised_sol: u64,
+    /// Target amount
+    pub target_sol: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted on demand with a wallet's whitelist eligibility for a pool
+#[event]
+pub struct WhitelistChecked {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Wallet that was checked
+    pub user: Pubkey,
+    /// Whether the proof places the wallet on the whitelist (always true without one)
+    pub eligible: bool,
+    /// Whether the pool restricts participation to a whitelist
+    pub has_whitelist: bool,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a terminal launch's financial summary is written
+#[event]
+pub struct LaunchSummarized {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Summary account
+    pub summary: Pubkey,
+    /// Terminal status
+    pub status: u8, // LaunchStatus as u8
+    /// Total amount raised
+    pub raised_sol: u64,
+    /// SOL deposited as liquidity
+    pub liquidity_sol: u64,
+    /// Excess SOL above the liquidity deposit
+    pub excess_sol: u64,
+    /// Excess SOL refunded or swept so far
+    pub excess_distributed: u64,
+    /// Tokens allocated to the creator
+    pub creator_allocation: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a launch that narrowly missed its target is extended instead of failing
+#[event]
+pub struct LaunchExtended {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// End time before the extension
+    pub previous_end_time: i64,
+    /// End time after the extension
+    pub new_end_time: i64,
+    /// Total amount raised at extension time
+    pub raised_amount: u64,
+    /// Target amount
+    pub target_amount: u64,
+    /// Extensions applied so far (including this one)
+    pub extensions_used: u8,
+    /// Extension timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a creator bond is refunded on migration or slashed on failure
+#[event]
+pub struct CreatorBondSettled {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Creator address
+    pub creator: Pubkey,
+    /// Account that received the bond
+    pub recipient: Pubkey,
+    /// Bond amount (lamports)
+    pub amount: u64,
+    /// Whether the bond was slashed to the treasury
+    pub slashed: bool,
+    /// Settlement timestamp
+    pub timestamp: i64,
+}
+
 // =============================================================================
 // TOKEN CLAIM EVENTS
 // =============================================================================
@@ -165,6 +332,19 @@ pub struct UserRefunded {
     pub timestamp: i64,
 }
 
+/// Event emitted when a settled user position is closed and its rent returned
+#[event]
+pub struct UserPositionClosed {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// User address
+    pub user: Pubkey,
+    /// Lamports returned to the user
+    pub rent_returned: u64,
+    /// Close timestamp
+    pub timestamp: i64,
+}
+
 // =============================================================================
 // STAKING EVENTS (IMPROVED)
 // =============================================================================
@@ -190,6 +370,8 @@ pub struct TokensStaked {
     pub stake_time: i64,
     /// Whether this is additional stake to existing position
     pub is_additional_stake: bool,
+    /// Current reward epoch for the token (0 if none opened)
+    pub epoch_index: u64,
 }
 
 /// Event emitted when tokens are unstaked
@@ -209,6 +391,133 @@ pub struct TokensUnstaked {
     pub duration_staked: i64,
     /// Timestamp when unstake occurred
     pub unstake_time: i64,
+    /// Current reward epoch for the token (0 if none opened)
+    pub epoch_index: u64,
+}
+
+/// Event emitted when a staking position is moved to another wallet
+#[event]
+pub struct StakePositionTransferred {
+    /// Previous owner
+    pub from: Pubkey,
+    /// New owner
+    pub to: Pubkey,
+    /// Closed staking position account
+    pub old_position: Pubkey,
+    /// New staking position account
+    pub new_position: Pubkey,
+    /// Token mint address of the staked token
+    pub token_mint: Pubkey,
+    /// Amount of tokens staked in the position
+    pub staked_amount: u64,
+    /// Timestamp when tokens can be unlocked
+    pub unlock_time: i64,
+    /// Timestamp of the transfer
+    pub transfer_time: i64,
+}
+
+/// Event emitted when a position staked before positions were indexed moves to an indexed PDA
+#[event]
+pub struct StakePositionMigrated {
+    /// Owner of the staking position
+    pub user: Pubkey,
+    /// Closed legacy staking position account
+    pub legacy_position: Pubkey,
+    /// New indexed staking position account
+    pub position: Pubkey,
+    /// Token mint address of the staked token
+    pub token_mint: Pubkey,
+    /// Index assigned to the position
+    pub position_index: u64,
+    /// Amount of tokens staked in the position
+    pub staked_amount: u64,
+    /// Timestamp when tokens can be unlocked
+    pub unlock_time: i64,
+}
+
+/// Event emitted when a staking position's lock is extended
+#[event]
+pub struct StakeLockExtended {
+    /// Owner of the staking position
+    pub user: Pubkey,
+    /// Staking position account
+    pub position: Pubkey,
+    /// Token mint address of the staked token
+    pub token_mint: Pubkey,
+    /// Unlock time before the extension
+    pub old_unlock_time: i64,
+    /// Unlock time after the extension
+    pub new_unlock_time: i64,
+    /// Total lock duration after the extension
+    pub lock_duration: i64,
+    /// Timestamp of the extension
+    pub timestamp: i64,
+}
+
+/// Event emitted when a reward epoch is opened for a staked token
+#[event]
+pub struct RewardEpochOpened {
+    /// Reward epoch account
+    pub reward_epoch: Pubkey,
+    /// Token mint address of the staked token
+    pub token_mint: Pubkey,
+    /// Index of the new epoch
+    pub epoch_index: u64,
+    /// Total amount staked for the token at the epoch boundary
+    pub total_staked: u64,
+    /// Timestamp when the epoch was opened
+    pub opened_at: i64,
+}
+
+/// Event emitted when the staking reward stream of a token is created or its rate changed
+#[event]
+pub struct StakingRewardsConfigured {
+    /// Staking reward config account
+    pub config: Pubkey,
+    /// Token mint address of the staked token
+    pub token_mint: Pubkey,
+    /// Token mint the rewards are paid in
+    pub reward_mint: Pubkey,
+    /// Reward rate before the change (0 when created)
+    pub previous_reward_rate: u64,
+    /// Reward tokens streamed per second
+    pub reward_rate: u64,
+    /// Timestamp of the change
+    pub timestamp: i64,
+}
+
+/// Event emitted when a token's staking reward stream is funded
+#[event]
+pub struct StakingRewardsFunded {
+    /// Staking reward config account
+    pub config: Pubkey,
+    /// Account that funded the rewards
+    pub funder: Pubkey,
+    /// Amount of rewards funded
+    pub amount: u64,
+    /// Funded rewards not yet streamed after this deposit
+    pub reward_budget: u64,
+    /// Timestamp of the deposit
+    pub timestamp: i64,
+}
+
+/// Event emitted when a staker claims accrued staking rewards
+#[event]
+pub struct StakingRewardsClaimed {
+    /// Owner of the staking position
+    pub user: Pubkey,
+    /// Staking position account
+    pub position: Pubkey,
+    /// Token mint address of the staked token
+    pub token_mint: Pubkey,
+    /// Token mint the rewards are paid in
+    pub reward_mint: Pubkey,
+    /// Amount of rewards claimed
+    pub amount: u64,
+    /// Accrued rewards dropped because the vault could not cover them (unstake only)
+    pub forfeited_amount: u64,
+    /// Timestamp of the claim
+    pub timestamp: i64,
 }
 
 /// Event emitted when liquidity pool is created on Meteora
@@ -232,6 +541,74 @@ pub struct LiquidityPoolCreated {
     pub timestamp: i64,
 }
 
+/// Event emitted when a Meteora pool config is checked ahead of migration
+#[event]
+pub struct PoolConfigValidated {
+    /// Launch pool address
+    pub launch_pool: Pubkey,
+    /// cp_amm config account
+    pub pool_config: Pubkey,
+    /// Authority allowed to create pools with this config
+    pub pool_creator_authority: Pubkey,
+    /// Config type (0 static, 1 dynamic)
+    pub config_type: u8,
+    /// Activation type
+    pub activation_type: u8,
+    /// Collect fee mode
+    pub collect_fee_mode: u8,
+    /// Base fee cliff numerator
+    pub cliff_fee_numerator: u64,
+    /// Minimum sqrt price
+    pub sqrt_min_price: u128,
+    /// Maximum sqrt price
+    pub sqrt_max_price: u128,
+}
+
+/// Event emitted when the creator fee recipient of a launch is overridden or reset
+#[event]
+pub struct CreatorFeeRecipientSet {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Creator address
+    pub creator: Pubkey,
+    /// Recipient now receiving the creator fee share
+    pub recipient: Pubkey,
+    /// Whether an override is active
+    pub is_override: bool,
+    /// Admin who made the change
+    pub admin: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when the admin pauses or resumes contributions to a pool
+#[event]
+pub struct PoolPausedSet {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Whether the pool is now paused
+    pub paused: bool,
+    /// Admin who made the change
+    pub admin: Pubkey,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when escrowed creator pool fees are withdrawn
+#[event]
+pub struct CreatorFeesWithdrawn {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Recipient who withdrew the fees
+    pub recipient: Pubkey,
+    /// Token mint withdrawn
+    pub token_mint: Pubkey,
+    /// Amount withdrawn
+    pub amount: u64,
+    /// Timestamp
+    pub timestamp: i64,
+}
+
 // =============================================================================
 // SWAP EVENTS
 // =============================================================================
@@ -253,7 +630,7 @@ pub struct SwapFeeCharged {
     pub actual_swap_amount: u64,
     /// Amount of output tokens received by user
     pub amount_out: u64,
-    /// Fee percentage in basis points (5 = 0.05%)
+    /// Configured fee in basis points (5 = 0.05%)
     pub fee_percentage: u16,
     /// Timestamp of the swap
     pub timestamp: i64,
@@ -274,6 +651,85 @@ pub struct DividendClaimed {
     pub claimed_amount: u64,
     /// Total amount of dividends this user has claimed for this token
     pub total_claimed: u64,
+    /// Total amount of dividends distributed to all users for this token
+    pub total_distributed: u64,
+    /// Signed total dividend amount used for verification
+    pub signed_total_dividend: u64,
+    /// Claim timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when dividend tokens are deposited into the dividend vault
+#[event]
+pub struct DividendsDeposited {
+    /// Account that funded the deposit
+    pub depositor: Pubkey,
+    /// Token mint of the dividends
+    pub token_mint: Pubkey,
+    /// Amount deposited in this transaction
+    pub amount: u64,
+    /// Total amount deposited for this token
+    pub total_deposited: u64,
+    /// Total amount claimed by users for this token
+    pub total_distributed: u64,
+    /// Deposit timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a pool's undistributed excess SOL becomes a SOL dividend campaign
+#[event]
+pub struct ExcessConvertedToDividends {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Token mint the SOL dividends are keyed by
+    pub token_mint: Pubkey,
+    /// Amount of SOL moved into the dividend vault
+    pub amount: u64,
+    /// Excess SOL already claimed by participants before conversion
+    pub already_distributed: u64,
+    /// Conversion timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when excess SOL rounding dust is swept to treasury
+#[event]
+pub struct ExcessDustSwept {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Dust amount swept (lamports)
+    pub amount: u64,
+    /// Total excess SOL of the pool
+    pub excess_sol: u64,
+    /// Sweep timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when unclaimed sale tokens are swept to treasury
+#[event]
+pub struct UnsoldTokensSwept {
+    /// Launch pool address
+    pub pool: Pubkey,
+    /// Token mint
+    pub token_mint: Pubkey,
+    /// Amount of sale tokens swept
+    pub amount: u64,
+    /// Sale tokens claimed by participants before the sweep
+    pub total_tokens_claimed: u64,
+    /// Sweep timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when user claims SOL dividends
+#[event]
+pub struct SolDividendClaimed {
+    /// User address who claimed dividends
+    pub user: Pubkey,
+    /// Token mint the SOL dividends are keyed by
+    pub token_mint: Pubkey,
+    /// Amount of SOL claimed in this transaction
+    pub claimed_amount: u64,
+    /// Total amount of SOL dividends this user has claimed for this token
+    pub total_claimed: u64,
     /// Signed total dividend amount used for verification
     pub signed_total_dividend: u64,
     /// Claim timestamp
@@ -300,3 +756,145 @@ pub struct LiquidityLocked {
     /// Lock timestamp
     pub timestamp: i64,
 }
+
+// =============================================================================
+// CONFIG EVENTS
+// =============================================================================
+
+/// Event emitted when a terminal launch pool stops counting against the global config
+#[event]
+pub struct LaunchPoolSettled {
+    /// Launch pool account
+    pub pool: Pubkey,
+    /// Status the pool settled in
+    pub status: u8,
+    /// SOL refunded to participants (failed launches only)
+    pub total_refunded: u64,
+    /// Launch pools still active under the global config
+    pub active_pool_count: u64,
+    /// Settlement timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when the global config is closed ahead of reinitialization
+#[event]
+pub struct GlobalConfigClosed {
+    /// Admin who closed the config
+    pub admin: Pubkey,
+    /// Number of launches created under the closed config
+    pub pool_count: u64,
+    /// Close timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when update_config changes any field
+/// Only changed fields are Some, in both the previous and updated values
+#[event]
+pub struct ConfigUpdated {
+    /// Admin who updated the config
+    pub admin: Pubkey,
+    /// Values of the changed fields before the update
+    pub previous: UpdateConfigParams,
+    /// Values of the changed fields after the update
+    pub updated: UpdateConfigParams,
+    /// Update timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when sensitive config changes are staged behind the timelock
+#[event]
+pub struct ConfigChangeStaged {
+    /// Admin who staged the changes
+    pub admin: Pubkey,
+    /// Staged points_signer
+    pub pending_points_signer: Option<Pubkey>,
+    /// Staged lb_pair
+    pub pending_lb_pair: Option<Pubkey>,
+    /// Staged config_timelock
+    pub pending_config_timelock: Option<i64>,
+    /// Time the changes can be applied
+    pub effective_at: i64,
+    /// Staging timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when staged config changes are applied
+#[event]
+pub struct ConfigChangeApplied {
+    /// Admin who applied the changes
+    pub admin: Pubkey,
+    /// Applied points_signer
+    pub points_signer: Option<Pubkey>,
+    /// Applied lb_pair
+    pub lb_pair: Option<Pubkey>,
+    /// Applied config_timelock
+    pub config_timelock: Option<i64>,
+    /// Apply timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when the admin proposes a new admin
+#[event]
+pub struct AdminProposed {
+    /// Current admin
+    pub admin: Pubkey,
+    /// Proposed admin, must call accept_admin
+    pub pending_admin: Pubkey,
+    /// Proposal timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when an account created under an older layout is grown to the current one
+#[event]
+pub struct AccountMigrated {
+    /// Migrated account
+    pub account: Pubkey,
+    /// Data length before the migration
+    pub old_size: u64,
+    /// Data length after the migration
+    pub new_size: u64,
+    /// Migration timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when a proposed admin accepts and takes over
+#[event]
+pub struct AdminTransferred {
+    /// Previous admin
+    pub previous_admin: Pubkey,
+    /// New admin
+    pub new_admin: Pubkey,
+    /// Transfer timestamp
+    pub timestamp: i64,
+}
+
+/// Event emitted when the admin recovers funds from a vault while the platform is paused
+#[event]
+pub struct EmergencyWithdraw {
+    /// Admin who withdrew the funds
+    pub admin: Pubkey,
+    /// Launch pool the vault belongs to, if any
+    pub pool: Option<Pubkey>,
+    /// Vault-authority-owned token account the funds came from
+    pub source: Pubkey,
+    /// Admin token account receiving the funds
+    pub destination: Pubkey,
+    /// Token mint
+    pub mint: Pubkey,
+    /// Amount withdrawn
+    pub amount: u64,
+    /// Withdraw timestamp
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SymbolReleased {
+    /// Launch pool that held the symbol
+    pub pool: Pubkey,
+    /// Hash of the normalized token symbol
+    pub symbol_hash: [u8; 32],
+    /// Creator refunded the registry rent
+    pub creator: Pubkey,
+    /// Release timestamp
+    pub timestamp: i64,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::GLOBAL_CONFIG_SEED;
+use crate::errors::LaunchpadError;
+use crate::events::AdminTransferred;
+use crate::state::GlobalConfig;
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pub new_admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        constraint = global_config.pending_admin != Pubkey::default()
+            && global_config.pending_admin == new_admin.key() @ LaunchpadError::Unauthorized,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+}
+
+/// Accept a pending admin proposal (pending admin only)
+pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+    let clock = Clock::get()?;
+
+    let previous_admin = config.admin;
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    emit!(AdminTransferred {
+        previous_admin,
+        new_admin: config.admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin transferred from {} to {}", previous_admin, config.admin);
+
+    Ok(())
+}
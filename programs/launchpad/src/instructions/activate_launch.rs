@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LAUNCH_POOL_SEED;
+use crate::errors::LaunchpadError;
+use crate::events::LaunchStatusChanged;
+use crate::state::{LaunchPool, LaunchStatus};
+
+#[derive(Accounts)]
+pub struct ActivateLaunch<'info> {
+    /// Anyone can activate a launch once its start time has passed
+    pub authority: Signer<'info>,
+
+    /// Scheduled launch pool
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_initialized() @ LaunchpadError::InvalidLaunchStatus,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+}
+
+/// Open a scheduled launch for contributions once its start_time is reached (permissionless)
+pub fn activate_launch(ctx: Context<ActivateLaunch>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= launch_pool.start_time,
+        LaunchpadError::NotStarted
+    );
+
+    let previous_status = launch_pool.status as u8;
+    launch_pool.status = LaunchStatus::Active;
+
+    emit!(LaunchStatusChanged {
+        pool: launch_pool.key(),
+        previous_status,
+        new_status: launch_pool.status as u8,
+        raised_amount: launch_pool.raised_sol,
+        target_amount: launch_pool.target_sol,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Launch {} activated", launch_pool.key());
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::GLOBAL_CONFIG_SEED;
+use crate::errors::LaunchpadError;
+use crate::events::ConfigChangeApplied;
+use crate::state::GlobalConfig;
+
+#[derive(Accounts)]
+pub struct ApplyConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ LaunchpadError::Unauthorized,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+}
+
+/// Apply sensitive config changes staged by update_config once the timelock has passed (admin only)
+pub fn apply_config(ctx: Context<ApplyConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+    let clock = Clock::get()?;
+
+    require!(config.has_pending_changes(), LaunchpadError::NoPendingConfigChange);
+    require!(
+        clock.unix_timestamp >= config.pending_effective_at,
+        LaunchpadError::ConfigTimelockActive
+    );
+
+    let points_signer = config.pending_points_signer;
+    let lb_pair = config.pending_lb_pair;
+    let config_timelock = config.pending_config_timelock;
+
+    if let Some(points_signer) = points_signer {
+        config.points_signer = points_signer;
+    }
+
+    if let Some(lb_pair) = lb_pair {
+        config.lb_pair = lb_pair;
+    }
+
+    if let Some(config_timelock) = config_timelock {
+        config.config_timelock = config_timelock;
+    }
+
+    config.clear_pending_changes();
+
+    emit!(ConfigChangeApplied {
+        admin: ctx.accounts.admin.key(),
+        points_signer,
+        lb_pair,
+        config_timelock,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Staged config changes applied");
+
+    Ok(())
+}
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Token, TokenAccount};
+use anchor_spl::token_interface::{self, Mint, TokenInterface};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::{LAUNCH_POOL_SEED, TOKEN_VAULT, VAULT_AUTHORITY};
+use crate::errors::LaunchpadError;
+use crate::events::LaunchStatusChanged;
+use crate::instructions::close_symbol_registry;
+use crate::state::{LaunchPool, LaunchStatus, SymbolRegistry};
+use crate::utils::settle_creator_bond;
+
+#[derive(Accounts)]
+pub struct CancelLaunch<'info> {
+    /// Launch creator, receives reclaimed rent and the creator bond
+    #[account(
+        mut,
+        address = launch_pool.creator @ LaunchpadError::NotCreator,
+    )]
+    pub creator: Signer<'info>,
+
+    /// Launch pool account, must not have started
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_initialized() || launch_pool.is_active() @ LaunchpadError::LaunchNotActive,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Launch token mint
+    #[account(
+        mut,
+        address = launch_pool.token_mint @ LaunchpadError::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Launch pool token vault, burned and closed
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT, launch_pool.key().as_ref(), vault_authority.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = vault_authority,
+        token::token_program = token_program,
+    )]
+    pub token_vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// CHECK: WSOL mint (verified by address)
+    #[account(address = anchor_spl::token::spl_token::native_mint::ID)]
+    pub wsol_mint: UncheckedAccount<'info>,
+
+    /// Launch pool WSOL vault, closed
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT, launch_pool.key().as_ref(), vault_authority.key().as_ref(), wsol_mint.key().as_ref()],
+        bump,
+        token::mint = wsol_mint,
+        token::authority = vault_authority,
+    )]
+    pub wsol_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Token program of the launch token, classic SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Token program of the WSOL vault, WSOL is always a classic SPL Token mint
+    pub quote_token_program: Program<'info, Token>,
+
+    /// Symbol registry held by the launch, closed when symbol uniqueness was enforced at creation
+    #[account(
+        mut,
+        constraint = symbol_registry.launch_pool == launch_pool.key() @ LaunchpadError::InvalidLaunchPool,
+    )]
+    pub symbol_registry: Option<Box<Account<'info, SymbolRegistry>>>,
+}
+
+/// Abort a launch before it starts (creator only)
+/// Burns the minted supply, closes both vaults, releases the symbol and refunds the creator bond
+pub fn cancel_launch(ctx: Context<CancelLaunch>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp < ctx.accounts.launch_pool.start_time,
+        LaunchpadError::LaunchAlreadyStarted
+    );
+    require!(
+        ctx.accounts.launch_pool.raised_sol == 0,
+        LaunchpadError::InvalidStatus
+    );
+
+    let signer_seeds = vault_signer_seeds(ctx.bumps.vault_authority);
+
+    // Burn the supply held in the token vault so it can be closed
+    let token_amount = ctx.accounts.token_vault.amount;
+    if token_amount > 0 {
+        token_interface::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token_interface::Burn {
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    from: ctx.accounts.token_vault.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            token_amount,
+        )?;
+    }
+
+    // Close both vaults through their own token programs, returning their rent to the creator
+    for (vault, token_program) in [
+        (ctx.accounts.token_vault.to_account_info(), ctx.accounts.token_program.to_account_info()),
+        (ctx.accounts.wsol_vault.to_account_info(), ctx.accounts.quote_token_program.to_account_info()),
+    ] {
+        token_interface::close_account(CpiContext::new_with_signer(
+            token_program,
+            token_interface::CloseAccount {
+                account: vault,
+                destination: ctx.accounts.creator.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+    }
+
+    if let Some(symbol_registry) = &ctx.accounts.symbol_registry {
+        close_symbol_registry(symbol_registry, &ctx.accounts.creator.to_account_info())?;
+    }
+
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    settle_creator_bond(launch_pool, &ctx.accounts.creator.to_account_info(), false)?;
+
+    let previous_status = launch_pool.status as u8;
+    launch_pool.status = LaunchStatus::Failed;
+    launch_pool.finalized_time = clock.unix_timestamp;
+
+    emit!(LaunchStatusChanged {
+        pool: launch_pool.key(),
+        previous_status,
+        new_status: launch_pool.status as u8,
+        raised_amount: launch_pool.raised_sol,
+        target_amount: launch_pool.target_sol,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Launch {} cancelled before start, burned {} tokens", launch_pool.key(), token_amount);
+
+    Ok(())
+}
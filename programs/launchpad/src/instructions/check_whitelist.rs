@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LAUNCH_POOL_SEED;
+use crate::events::WhitelistChecked;
+use crate::state::LaunchPool;
+
+#[derive(Accounts)]
+pub struct CheckWhitelist<'info> {
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+}
+
+/// Emit whether `user` passes the pool's whitelist with `proof` (permissionless, read-only)
+/// Lets clients validate a proof before building a participation transaction
+pub fn check_whitelist(ctx: Context<CheckWhitelist>, user: Pubkey, proof: Vec<[u8; 32]>) -> Result<()> {
+    let launch_pool = &ctx.accounts.launch_pool;
+    let eligible = launch_pool.is_whitelisted(&user, &proof);
+
+    emit!(WhitelistChecked {
+        pool: launch_pool.key(),
+        user,
+        eligible,
+        has_whitelist: launch_pool.whitelist_root.is_some(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Whitelist check for {}: {}", user, if eligible { "eligible" } else { "not eligible" });
+
+    Ok(())
+}
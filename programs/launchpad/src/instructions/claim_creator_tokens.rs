@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
 
-use crate::const_pda::const_authority::VAULT_BUMP;
+use crate::const_pda::const_authority::vault_signer_seeds;
 use crate::constants::{LAUNCH_POOL_SEED, TOKEN_VAULT, VAULT_AUTHORITY};
 use crate::errors::LaunchpadError;
 use crate::state::{LaunchPool, LaunchStatus};
@@ -33,6 +33,13 @@ pub struct ClaimCreatorTokens<'info> {
     )]
     pub launch_pool: Box<Account<'info, LaunchPool>>,
 
+    /// Launch token mint
+    #[account(
+        address = launch_pool.token_mint @ LaunchpadError::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
     /// Launch pool token vault
     #[account(
         mut,
@@ -40,20 +47,23 @@ pub struct ClaimCreatorTokens<'info> {
         bump,
         token::mint = launch_pool.token_mint,
         token::authority = vault_authority,
+        token::token_program = token_program,
         address = launch_pool.token_vault,
         constraint = launch_pool.token_vault == pool_token_vault.key() @ LaunchpadError::InvalidTokenVault
     )]
-    pub pool_token_vault: Box<Account<'info, TokenAccount>>,
+    pub pool_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// Creator token receiving account
     #[account(
         mut,
         token::mint = launch_pool.token_mint,
         token::authority = creator,
+        token::token_program = token_program,
     )]
-    pub creator_token_account: Box<Account<'info, TokenAccount>>,
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    /// Token program of the launch token, classic SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 /// Creator claim tokens (supports batch claiming)
@@ -78,23 +88,51 @@ pub fn claim_creator_tokens(ctx: Context<ClaimCreatorTokens>) -> Result<()> {
     msg!("Total claimed so far: {} tokens", launch_pool.creator_claimed_tokens);
 
     // Execute token transfer
-    let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
-    token::transfer(
+    let signer_seeds = vault_signer_seeds(ctx.bumps.vault_authority);
+    token_interface::transfer_checked(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            Transfer {
+            TransferChecked {
                 from: ctx.accounts.pool_token_vault.to_account_info(),
+                mint: ctx.accounts.token_mint.to_account_info(),
                 to: ctx.accounts.creator_token_account.to_account_info(),
                 authority: ctx.accounts.vault_authority.to_account_info(),
             },
             signer_seeds,
         ),
         claimable_amount,
+        ctx.accounts.token_mint.decimals,
+    )?;
+
+    let pool_key = launch_pool.key();
+    let remaining_claimable = record_creator_claim(
+        launch_pool,
+        pool_key,
+        ctx.accounts.creator.key(),
+        claimable_amount,
+        current_time,
     )?;
 
+    msg!("Creator tokens claimed successfully");
+    msg!("Claimed amount: {} tokens", claimable_amount);
+    msg!("Total claimed: {} tokens", launch_pool.creator_claimed_tokens);
+    msg!("Remaining allocation: {} tokens", remaining_claimable);
+
+    Ok(())
+}
+
+/// Add a transferred creator claim to the pool's claimed amount and emit CreatorTokensClaimed
+/// Returns the creator allocation still left to claim
+pub fn record_creator_claim(
+    launch_pool: &mut LaunchPool,
+    pool_key: Pubkey,
+    creator: Pubkey,
+    claimed_amount: u64,
+    current_time: i64,
+) -> Result<u64> {
     // Update claimed amount
     launch_pool.creator_claimed_tokens = launch_pool.creator_claimed_tokens
-        .checked_add(claimable_amount)
+        .checked_add(claimed_amount)
         .ok_or(LaunchpadError::MathOverflow)?;
 
     // Calculate remaining claimable amount
@@ -104,10 +142,10 @@ pub fn claim_creator_tokens(ctx: Context<ClaimCreatorTokens>) -> Result<()> {
 
     // Emit creator tokens claimed event
     emit!(CreatorTokensClaimed {
-        pool: launch_pool.key(),
-        creator: ctx.accounts.creator.key(),
+        pool: pool_key,
+        creator,
         token_mint: launch_pool.token_mint,
-        claimed_amount: claimable_amount,
+        claimed_amount,
         total_claimed: launch_pool.creator_claimed_tokens,
         total_allocation: launch_pool.creator_allocation,
         remaining_claimable,
@@ -115,10 +153,5 @@ pub fn claim_creator_tokens(ctx: Context<ClaimCreatorTokens>) -> Result<()> {
         timestamp: current_time,
     });
 
-    msg!("Creator tokens claimed successfully");
-    msg!("Claimed amount: {} tokens", claimable_amount);
-    msg!("Total claimed: {} tokens", launch_pool.creator_claimed_tokens);
-    msg!("Remaining allocation: {} tokens", remaining_claimable);
-
-    Ok(())
+    Ok(remaining_claimable)
 }
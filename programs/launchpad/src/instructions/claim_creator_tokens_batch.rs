@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::{MAX_CREATOR_CLAIM_BATCH_SIZE, VAULT_AUTHORITY};
+use crate::errors::LaunchpadError;
+use crate::instructions::record_creator_claim;
+use crate::state::{LaunchPool, LaunchStatus};
+
+/// Each pool in the batch is passed as four remaining accounts:
+/// launch pool, launch token mint, pool token vault, creator token account for the pool's mint
+/// All but the mint must be writable
+#[derive(Accounts)]
+pub struct ClaimCreatorTokensBatch<'info> {
+    /// Creator of every pool in the batch
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    /// vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Token program of the launch tokens, classic SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Claim the vested creator tokens of several migrated pools in one transaction
+/// Pools with nothing claimable yet are skipped, any invalid account aborts the whole batch
+pub fn claim_creator_tokens_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimCreatorTokensBatch<'info>>,
+) -> Result<()> {
+    let batch_size = ctx.remaining_accounts.len() / 4;
+    require!(
+        batch_size > 0
+            && batch_size <= MAX_CREATOR_CLAIM_BATCH_SIZE
+            && ctx.remaining_accounts.len() == batch_size * 4,
+        LaunchpadError::InvalidBatchSize
+    );
+
+    let creator = ctx.accounts.creator.key();
+    let current_time = Clock::get()?.unix_timestamp;
+    let signer_seeds = vault_signer_seeds(ctx.bumps.vault_authority);
+    let mut seen_pools: Vec<Pubkey> = Vec::with_capacity(batch_size);
+    let mut claimed_pools: u32 = 0;
+
+    for accounts in ctx.remaining_accounts.chunks(4) {
+        let (pool_info, mint_info, vault_info, destination_info) =
+            (&accounts[0], &accounts[1], &accounts[2], &accounts[3]);
+        require!(
+            pool_info.is_writable && vault_info.is_writable && destination_info.is_writable,
+            LaunchpadError::InvalidLaunchPool
+        );
+
+        let mut launch_pool = LaunchPool::load_canonical(pool_info)?;
+
+        // A pool listed twice would have its first write overwritten by the second
+        require!(!seen_pools.contains(&pool_info.key()), LaunchpadError::InvalidLaunchPool);
+        seen_pools.push(pool_info.key());
+
+        require_keys_eq!(launch_pool.creator, creator, LaunchpadError::NotCreator);
+        require!(launch_pool.status == LaunchStatus::Migrated, LaunchpadError::InvalidStatus);
+
+        // Every account must belong to the token program passed in, a batch cannot mix the two
+        let token_program_key = ctx.accounts.token_program.key();
+        require!(
+            *mint_info.owner == token_program_key
+                && *vault_info.owner == token_program_key
+                && *destination_info.owner == token_program_key,
+            LaunchpadError::InvalidTokenProgram
+        );
+
+        require_keys_eq!(mint_info.key(), launch_pool.token_mint, LaunchpadError::InvalidTokenMint);
+        let token_mint: InterfaceAccount<'info, Mint> = InterfaceAccount::try_from(mint_info)?;
+
+        require_keys_eq!(vault_info.key(), launch_pool.token_vault, LaunchpadError::InvalidTokenVault);
+        let pool_token_vault: InterfaceAccount<'info, TokenAccount> = InterfaceAccount::try_from(vault_info)?;
+
+        let creator_token_account: InterfaceAccount<'info, TokenAccount> = InterfaceAccount::try_from(destination_info)?;
+        require_keys_eq!(creator_token_account.mint, launch_pool.token_mint, LaunchpadError::InvalidTokenMint);
+        require_keys_eq!(creator_token_account.owner, creator, LaunchpadError::NotCreator);
+
+        let claimable_amount = launch_pool.calculate_creator_claimable_amount(current_time);
+        if claimable_amount == 0 {
+            msg!("Skipping pool {}: nothing claimable yet", pool_info.key());
+            continue;
+        }
+
+        require!(
+            pool_token_vault.amount >= claimable_amount,
+            LaunchpadError::InsufficientLiquidity
+        );
+
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: pool_token_vault.to_account_info(),
+                    mint: token_mint.to_account_info(),
+                    to: creator_token_account.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            claimable_amount,
+            token_mint.decimals,
+        )?;
+
+        record_creator_claim(&mut launch_pool, pool_info.key(), creator, claimable_amount, current_time)?;
+        launch_pool.exit(&crate::ID)?;
+        claimed_pools += 1;
+    }
+
+    require!(claimed_pools > 0, LaunchpadError::NothingToClaim);
+
+    msg!("Creator claimed tokens from {} of {} pools", claimed_pools, batch_size);
+
+    Ok(())
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::SolDividendClaimed;
+use crate::instructions::verify_points_signature;
+use crate::state::{GlobalConfig, UserDividendRecord, UserPoint};
+use crate::utils::{dividend_payload, SOL_DIVIDEND_DOMAIN};
+
+#[derive(Accounts)]
+pub struct ClaimSolDividends<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Token mint the SOL dividends are keyed by
+    pub token_mint: Account<'info, Mint>,
+
+    /// CHECK: WSOL mint (verified by address)
+    #[account(
+        address = anchor_spl::token::spl_token::native_mint::ID
+    )]
+    pub wsol_mint: Account<'info, Mint>,
+
+    /// User points account (tracks how many dividend records the user has opened)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPoint::SIZE,
+        seeds = [USER_POINT_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub user_point: Box<Account<'info, UserPoint>>,
+
+    /// User's SOL dividend record for this token mint
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserDividendRecord::SIZE,
+        seeds = [USER_SOL_DIVIDEND_SEED, token_mint.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_dividend_record: Box<Account<'info, UserDividendRecord>>,
+
+    /// Vault authority PDA
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// SOL dividend vault for this token mint
+    #[account(
+        mut,
+        seeds = [SOL_DIVIDEND_SEED, token_mint.key().as_ref()],
+        bump,
+        token::mint = wsol_mint,
+        token::authority = vault_authority,
+        token::token_program = token_program
+    )]
+    pub sol_dividend_vault: Box<Account<'info, TokenAccount>>,
+
+    /// User's WSOL account to receive dividends
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = user,
+        token::token_program = token_program
+    )]
+    pub user_quote_account: Box<Account<'info, TokenAccount>>,
+
+    /// System variables account for Ed25519 signature verification
+    /// CHECK: This is a system-provided instruction system variable
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Distributes SOL dividends (e.g. converted excess SOL) calculated off-chain
+/// Users must provide points_signer's signature over their total SOL dividend for the token mint
+pub fn claim_sol_dividends(
+    ctx: Context<ClaimSolDividends>,
+    total_dividend_amount: u64,
+    expiry_ts: i64,
+    signature: [u8; 64],
+) -> Result<()> {
+    let user_dividend_record = &mut ctx.accounts.user_dividend_record;
+    let user = &ctx.accounts.user;
+    let token_mint = &ctx.accounts.token_mint;
+    let clock = Clock::get()?;
+
+    // Initialize dividend record if needed
+    if user_dividend_record.user == Pubkey::default() {
+        let user_point = &mut ctx.accounts.user_point;
+        user_point.user = user.key();
+        user_point.register_dividend_record(ctx.accounts.global_config.max_dividend_records_per_user)?;
+
+        user_dividend_record.user = user.key();
+        user_dividend_record.token_mint = token_mint.key();
+        user_dividend_record.bump = ctx.bumps.user_dividend_record;
+    }
+
+    // Reject signed totals past their deadline
+    require!(clock.unix_timestamp <= expiry_ts, LaunchpadError::SignatureExpired);
+
+    // Verify SOL dividend signature using points_signer
+    let payload = dividend_payload(&user.key(), &token_mint.key(), total_dividend_amount, expiry_ts);
+    verify_points_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.global_config.points_signer,
+        SOL_DIVIDEND_DOMAIN,
+        &payload,
+        &signature,
+    )?;
+
+    // Calculate claimable amount
+    let claimable_amount = user_dividend_record.calculate_claimable(total_dividend_amount)?;
+    require!(claimable_amount > 0, LaunchpadError::NoClaimableAmount);
+
+    // Check if vault has sufficient balance
+    require!(
+        ctx.accounts.sol_dividend_vault.amount >= claimable_amount,
+        LaunchpadError::InsufficientVaultBalance
+    );
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.sol_dividend_vault.to_account_info(),
+                to: ctx.accounts.user_quote_account.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            vault_signer_seeds(ctx.bumps.vault_authority),
+        ),
+        claimable_amount,
+    )?;
+
+    // Update user dividend record
+    user_dividend_record.update_claim(claimable_amount, clock.unix_timestamp)?;
+
+    emit!(SolDividendClaimed {
+        user: user.key(),
+        token_mint: token_mint.key(),
+        claimed_amount: claimable_amount,
+        total_claimed: user_dividend_record.total_claimed,
+        signed_total_dividend: total_dividend_amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("User {} claimed {} SOL dividends of mint {}",
+         user.key(), claimable_amount, token_mint.key());
+
+    Ok(())
+}
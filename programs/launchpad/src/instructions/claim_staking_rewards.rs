@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::VAULT_AUTHORITY;
+use crate::errors::LaunchpadError;
+use crate::events::StakingRewardsClaimed;
+use crate::state::{StakingPosition, StakingRewardConfig};
+
+#[derive(Accounts)]
+#[instruction(position_index: u64)]
+pub struct ClaimStakingRewards<'info> {
+    /// Owner of the staking position
+    pub user: Signer<'info>,
+
+    /// vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Token mint of the staked token
+    pub token_mint: Account<'info, Mint>,
+
+    /// Staking position earning the rewards
+    #[account(
+        mut,
+        seeds = [
+            StakingPosition::SEED,
+            user.key().as_ref(),
+            token_mint.key().as_ref(),
+            &position_index.to_le_bytes()
+        ],
+        bump = staking_position.bump,
+        constraint = staking_position.user == user.key() @ LaunchpadError::NoStakeFound,
+        constraint = staking_position.token_mint == token_mint.key() @ LaunchpadError::InvalidStakingTokenMint,
+    )]
+    pub staking_position: Account<'info, StakingPosition>,
+
+    /// Reward accumulator for the staked token
+    #[account(
+        mut,
+        seeds = [StakingRewardConfig::SEED, token_mint.key().as_ref()],
+        bump = staking_reward_config.bump,
+    )]
+    pub staking_reward_config: Account<'info, StakingRewardConfig>,
+
+    /// Treasury-funded reward vault
+    #[account(
+        mut,
+        seeds = [StakingRewardConfig::VAULT_SEED, staking_reward_config.key().as_ref()],
+        bump,
+        token::mint = staking_reward_config.reward_mint,
+        token::authority = vault_authority,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// User's account receiving the rewards
+    #[account(
+        mut,
+        token::mint = staking_reward_config.reward_mint,
+        token::authority = user,
+    )]
+    pub user_reward_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pay out a staking position's accrued rewards from the reward vault
+/// Also enrolls the position's stake in the accumulator if it was staked before rewards existed
+pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>, _position_index: u64) -> Result<()> {
+    let config = &mut ctx.accounts.staking_reward_config;
+    let staking_position = &mut ctx.accounts.staking_position;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    config.update_rewards(current_time)?;
+    config.settle_position(staking_position)?;
+
+    let amount = staking_position.pending_rewards;
+    require!(amount > 0, LaunchpadError::NothingToClaim);
+    require!(
+        ctx.accounts.reward_vault.amount >= amount,
+        LaunchpadError::InsufficientVaultBalance
+    );
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.reward_vault.to_account_info(),
+                to: ctx.accounts.user_reward_account.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            vault_signer_seeds(ctx.bumps.vault_authority),
+        ),
+        amount,
+    )?;
+    staking_position.pending_rewards = 0;
+
+    emit!(StakingRewardsClaimed {
+        user: ctx.accounts.user.key(),
+        position: staking_position.key(),
+        token_mint: staking_position.token_mint,
+        reward_mint: config.reward_mint,
+        amount,
+        forfeited_amount: 0,
+        timestamp: current_time,
+    });
+
+    msg!("User {} claimed {} staking rewards", ctx.accounts.user.key(), amount);
+
+    Ok(())
+}
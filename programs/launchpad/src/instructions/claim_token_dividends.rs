@@ -1,13 +1,13 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::sysvar;
-use anchor_lang::solana_program::sysvar::instructions::{load_instruction_at_checked, load_current_index_checked};
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
 
+use crate::const_pda::const_authority::vault_signer_seeds;
 use crate::constants::*;
 use crate::errors::LaunchpadError;
-use crate::state::{GlobalConfig, UserDividendRecord};
-use crate::utils::{format_dividend_message, verify_ed25519_ix};
+use crate::state::{DividendPool, GlobalConfig, UserDividendRecord, UserPoint};
+use crate::instructions::verify_points_signature;
+use crate::utils::{dividend_payload, DIVIDEND_DOMAIN};
 use crate::events::DividendClaimed;
 
 #[derive(Accounts)]
@@ -26,6 +26,16 @@ pub struct ClaimTokenDividends<'info> {
     /// Token mint for dividend distribution
     pub token_mint: Account<'info, Mint>,
 
+    /// User points account (tracks how many dividend records the user has opened)
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPoint::SIZE,
+        seeds = [USER_POINT_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub user_point: Box<Account<'info, UserPoint>>,
+
     /// User's dividend record for this token mint
     #[account(
         init_if_needed,
@@ -36,6 +46,16 @@ pub struct ClaimTokenDividends<'info> {
     )]
     pub user_dividend_record: Box<Account<'info, UserDividendRecord>>,
 
+    /// Running dividend totals for this token mint
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = DividendPool::SIZE,
+        seeds = [DividendPool::SEED, token_mint.key().as_ref()],
+        bump,
+    )]
+    pub dividend_pool: Box<Account<'info, DividendPool>>,
+
     /// Vault authority PDA
     /// CHECK: vault authority
     #[account(
@@ -75,11 +95,12 @@ pub struct ClaimTokenDividends<'info> {
 }
 
 /// This method distributes dividends calculated off-chain
-/// Sufficient token_mint must be transferred into dividend_vault before distribution
+/// Sufficient token_mint must be deposited into dividend_vault through deposit_dividends before distribution
 /// And users must require points_signer's signature to claim dividends
 pub fn claim_token_dividends(
     ctx: Context<ClaimTokenDividends>,
     total_dividend_amount: u64,
+    expiry_ts: i64,
     signature: [u8; 64],
 ) -> Result<()> {
     let user_dividend_record = &mut ctx.accounts.user_dividend_record;
@@ -89,21 +110,33 @@ pub fn claim_token_dividends(
 
     // Initialize dividend record if needed
     if user_dividend_record.user == Pubkey::default() {
+        let user_point = &mut ctx.accounts.user_point;
+        user_point.user = user.key();
+        user_point.register_dividend_record(ctx.accounts.global_config.max_dividend_records_per_user)?;
+
         user_dividend_record.user = user.key();
         user_dividend_record.token_mint = token_mint.key();
         user_dividend_record.bump = ctx.bumps.user_dividend_record;
     }
 
-    // Format the message for signature verification
-    let message = format_dividend_message(&user.key(), &token_mint.key(), total_dividend_amount);
+    let dividend_pool = &mut ctx.accounts.dividend_pool;
+    if dividend_pool.token_mint == Pubkey::default() {
+        dividend_pool.token_mint = token_mint.key();
+        dividend_pool.bump = ctx.bumps.dividend_pool;
+    }
 
-    // Get the current instruction index and load the previous instruction
-    let current_index = load_current_index_checked(&ctx.accounts.instructions_sysvar)?;
-    require!(current_index > 0, LaunchpadError::InvalidInstructionIndex);
-    let ix: Instruction = load_instruction_at_checked((current_index - 1) as usize, &ctx.accounts.instructions_sysvar)?;
+    // Reject signed totals past their deadline
+    require!(clock.unix_timestamp <= expiry_ts, LaunchpadError::SignatureExpired);
 
     // Verify dividend signature using points_signer
-    verify_ed25519_ix(&ix, &ctx.accounts.global_config.points_signer.to_bytes(), &message, &signature)?;
+    let payload = dividend_payload(&user.key(), &token_mint.key(), total_dividend_amount, expiry_ts);
+    verify_points_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.global_config.points_signer,
+        DIVIDEND_DOMAIN,
+        &payload,
+        &signature,
+    )?;
 
     // Calculate claimable amount
     let claimable_amount = user_dividend_record.calculate_claimable(total_dividend_amount)?;
@@ -117,12 +150,14 @@ pub fn claim_token_dividends(
         LaunchpadError::InsufficientVaultBalance
     );
 
+    // Never pay out more than was deposited, even if the signer issues totals beyond it
+    require!(
+        claimable_amount <= dividend_pool.remaining_deposit(),
+        LaunchpadError::InsufficientVaultBalance
+    );
+
     // Transfer dividends from vault to user
-    let vault_authority_seeds = &[
-        VAULT_AUTHORITY.as_ref(),
-        &[ctx.bumps.vault_authority],
-    ];
-    let vault_authority_signer = &[&vault_authority_seeds[..]];
+    let vault_authority_signer = vault_signer_seeds(ctx.bumps.vault_authority);
 
     token::transfer(
         CpiContext::new_with_signer(
@@ -139,6 +174,7 @@ pub fn claim_token_dividends(
 
     // Update user dividend record
     user_dividend_record.update_claim(claimable_amount, clock.unix_timestamp)?;
+    dividend_pool.record_claim(claimable_amount)?;
 
     // Emit dividend claimed event
     emit!(DividendClaimed {
@@ -146,6 +182,7 @@ pub fn claim_token_dividends(
         token_mint: token_mint.key(),
         claimed_amount: claimable_amount,
         total_claimed: user_dividend_record.total_claimed,
+        total_distributed: dividend_pool.total_distributed,
         signed_total_dividend: total_dividend_amount,
         timestamp: clock.unix_timestamp,
     });
@@ -1,17 +1,23 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+use anchor_spl::token_interface::{self, TokenInterface, TransferChecked};
 
-use crate::const_pda::const_authority::VAULT_BUMP;
-use crate::constants::{LAUNCH_POOL_SEED, USER_POSITION_SEED, VAULT_AUTHORITY};
-use crate::state::{LaunchPool, LaunchStatus, UserPosition};
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::{GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED, USER_POSITION_SEED, VAULT_AUTHORITY};
+use crate::state::{GlobalConfig, LaunchPool, LaunchStatus, UserPosition};
 use crate::errors::LaunchpadError;
 use crate::events::{UserRewardsClaimed, UserRefunded};
 
 #[derive(Accounts)]
 pub struct ClaimUserRewards<'info> {
-    #[account(mut)]
+    /// Position owner, rewards only flow to accounts owned by this user
     pub user: Signer<'info>,
 
+    /// Pays rent for missing ATAs, either the user or a relayer covering it for them
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
     /// vault authority
     #[account(
         mut,
@@ -22,6 +28,13 @@ pub struct ClaimUserRewards<'info> {
     )]
     pub vault_authority: SystemAccount<'info>,
 
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
     #[account(
         mut,
         seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
@@ -44,10 +57,11 @@ pub struct ClaimUserRewards<'info> {
         mut,
         token::mint = launch_pool.token_mint.key(),
         token::authority = vault_authority,
+        token::token_program = token_program,
         address = launch_pool.token_vault,
         constraint = launch_pool.token_vault == pool_token_vault.key() @ LaunchpadError::InvalidTokenVault
     )]
-    pub pool_token_vault: Box<Account<'info, TokenAccount>>,
+    pub pool_token_vault: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
 
     /// Pool's quote vault (SOL)
     #[account(
@@ -59,23 +73,43 @@ pub struct ClaimUserRewards<'info> {
     )]
     pub pool_quote_vault: Box<Account<'info, TokenAccount>>,
 
-    /// User's token account to receive tokens
+    /// Launch token mint
     #[account(
-        mut,
-        token::mint = launch_pool.token_mint.key(),
-        token::authority = user,
+        address = launch_pool.token_mint @ LaunchpadError::InvalidTokenMint,
+        mint::token_program = token_program,
     )]
-    pub user_token_account: Box<Account<'info, TokenAccount>>,
+    pub token_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
 
-    /// User's quote account to receive excess SOL
+    /// Quote (WSOL) mint
+    #[account(address = launch_pool.quote_mint @ LaunchpadError::InvalidQuoteMint)]
+    pub quote_mint: Box<Account<'info, Mint>>,
+
+    /// User's token ATA to receive tokens, created at the payer's expense if missing
     #[account(
-        mut,
-        token::mint = launch_pool.quote_mint.key(),
-        token::authority = user,
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = token_mint,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    /// User's WSOL ATA to receive excess SOL or refunds, created at the payer's expense if missing
+    #[account(
+        init_if_needed,
+        payer = payer,
+        associated_token::mint = quote_mint,
+        associated_token::authority = user,
+        associated_token::token_program = quote_token_program,
     )]
     pub user_quote_account: Box<Account<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    /// Token program of the launch token, classic SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+    /// Token program of the quote vault, WSOL is always a classic SPL Token mint
+    pub quote_token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
 }
 
 /// Claim rewards based on pool status - tokens and excess SOL for successful pools, only refund for failed pools
@@ -90,11 +124,13 @@ pub fn claim_user_rewards(ctx: Context<ClaimUserRewards>) -> Result<()> {
         return Err(LaunchpadError::AlreadyClaimed.into());
     }
 
-    let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
+    let signer_seeds = vault_signer_seeds(ctx.bumps.vault_authority);
 
     // Handle different pool statuses
     match pool.status {
         LaunchStatus::Failed => {
+            check_refund_open(pool.finalized_time, ctx.accounts.global_config.refund_delay, current_time)?;
+
             // For failed pools, only refund the contributed SOL
             let refund_amount = user_position.contributed_sol;
 
@@ -104,7 +140,7 @@ pub fn claim_user_rewards(ctx: Context<ClaimUserRewards>) -> Result<()> {
             if refund_amount > 0 {
                 token::transfer(
                     CpiContext::new_with_signer(
-                        ctx.accounts.token_program.to_account_info(),
+                        ctx.accounts.quote_token_program.to_account_info(),
                         Transfer {
                             from: ctx.accounts.pool_quote_vault.to_account_info(),
                             to: ctx.accounts.user_quote_account.to_account_info(),
@@ -119,6 +155,7 @@ pub fn claim_user_rewards(ctx: Context<ClaimUserRewards>) -> Result<()> {
             // Mark as refunded
             user_position.refunded = true;
             user_position.last_updated = current_time;
+            pool.record_refund(refund_amount)?;
 
             // Emit refund event
             emit!(UserRefunded {
@@ -134,35 +171,32 @@ pub fn claim_user_rewards(ctx: Context<ClaimUserRewards>) -> Result<()> {
             msg!("User refund processed successfully");
         },
         LaunchStatus::Migrated => {
+            // Sale tokens wait for the claim window, excess SOL only if the launch gates it too
+            let tokens_open = current_time >= pool.claim_start_time;
+            require!(tokens_open || !pool.gate_excess_claims, LaunchpadError::ClaimNotOpen);
+
             // For successful/migrated pools, distribute tokens and excess SOL
-            let tokens_to_claim = calculate_user_token_allocation(
-                user_position.contributed_sol,
-                pool.raised_sol,
-                pool.sale_allocation,
-            )?;
-
-            // Calculate excess SOL to claim
-            let excess_sol_to_claim = if pool.excess_sol > 0 && !user_position.excess_sol_claimed {
-                user_position.calculate_excess_sol(pool.excess_sol, pool.raised_sol)?
-            } else {
-                0
-            };
+            let (tokens_to_claim, excess_sol_to_claim) =
+                calculate_migrated_rewards(pool, user_position, current_time)?;
+            require!(tokens_open || excess_sol_to_claim > 0, LaunchpadError::ClaimNotOpen);
 
             msg!("User claiming: {} tokens, {} excess SOL", tokens_to_claim, excess_sol_to_claim);
 
             // Transfer tokens to user
             if tokens_to_claim > 0 {
-                token::transfer(
+                token_interface::transfer_checked(
                     CpiContext::new_with_signer(
                         ctx.accounts.token_program.to_account_info(),
-                        Transfer {
+                        TransferChecked {
                             from: ctx.accounts.pool_token_vault.to_account_info(),
+                            mint: ctx.accounts.token_mint.to_account_info(),
                             to: ctx.accounts.user_token_account.to_account_info(),
                             authority: ctx.accounts.vault_authority.to_account_info(),
                         },
                         signer_seeds,
                     ),
                     tokens_to_claim,
+                    ctx.accounts.token_mint.decimals,
                 )?;
             }
 
@@ -170,7 +204,7 @@ pub fn claim_user_rewards(ctx: Context<ClaimUserRewards>) -> Result<()> {
             if excess_sol_to_claim > 0 {
                 token::transfer(
                     CpiContext::new_with_signer(
-                        ctx.accounts.token_program.to_account_info(),
+                        ctx.accounts.quote_token_program.to_account_info(),
                         Transfer {
                             from: ctx.accounts.pool_quote_vault.to_account_info(),
                             to: ctx.accounts.user_quote_account.to_account_info(),
@@ -183,9 +217,16 @@ pub fn claim_user_rewards(ctx: Context<ClaimUserRewards>) -> Result<()> {
             }
 
             // Update user position
-            user_position.tokens_claimed = true;
-            if excess_sol_to_claim > 0 {
+            if tokens_open {
+                user_position.tokens_claimed = true;
+                pool.total_tokens_claimed = pool.total_tokens_claimed
+                    .checked_add(tokens_to_claim)
+                    .ok_or(LaunchpadError::MathOverflow)?;
+            }
+            // Settle the excess share even when it rounds to zero so dust sweeping can tell every position is done
+            if !user_position.excess_sol_claimed {
                 user_position.excess_sol_claimed = true;
+                pool.record_excess_distributed(excess_sol_to_claim)?;
             }
             user_position.last_updated = current_time;
 
@@ -212,6 +253,47 @@ pub fn claim_user_rewards(ctx: Context<ClaimUserRewards>) -> Result<()> {
     Ok(())
 }
 
+/// Tokens and excess SOL a position can claim from a migrated pool at `current_time`
+/// Tokens wait for claim_start_time, and anything already claimed, swept or converted counts as zero
+pub fn calculate_migrated_rewards(
+    pool: &LaunchPool,
+    user_position: &UserPosition,
+    current_time: i64,
+) -> Result<(u64, u64)> {
+    let tokens_to_claim = if current_time >= pool.claim_start_time
+        && !user_position.tokens_claimed
+        && !pool.unsold_tokens_swept
+    {
+        // Never distribute more than what is left of the sale allocation
+        calculate_user_token_allocation(
+            user_position.contributed_sol,
+            pool.raised_sol,
+            pool.sale_allocation,
+        )?
+        .min(pool.sale_allocation.saturating_sub(pool.total_tokens_claimed))
+    } else {
+        0
+    };
+
+    let excess_sol_to_claim = if pool.excess_sol > 0 && !user_position.excess_sol_claimed && !pool.excess_converted {
+        user_position.calculate_excess_sol(pool.excess_sol, pool.raised_sol)?
+    } else {
+        0
+    };
+
+    Ok((tokens_to_claim, excess_sol_to_claim))
+}
+
+/// Refunds open once the configured delay after finalization has passed
+fn check_refund_open(finalized_time: i64, refund_delay: i64, current_time: i64) -> Result<()> {
+    let refund_open_time = finalized_time
+        .checked_add(refund_delay)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    require!(current_time >= refund_open_time, LaunchpadError::RefundNotAvailable);
+
+    Ok(())
+}
+
 /// Calculate user's token allocation based on their SOL contribution
 fn calculate_user_token_allocation(
     user_contributed_sol: u64,
@@ -230,5 +312,35 @@ fn calculate_user_token_allocation(
         .checked_div(total_raised_sol as u128)
         .ok_or(LaunchpadError::MathOverflow)?;
 
-    Ok(user_tokens as u64)
+    let user_tokens = u64::try_from(user_tokens).map_err(|_| LaunchpadError::MathOverflow)?;
+
+    Ok(user_tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refund_opens_exactly_at_the_delay() {
+        let (finalized_time, refund_delay) = (1_000, 3_600);
+        assert!(check_refund_open(finalized_time, refund_delay, finalized_time + refund_delay - 1).is_err());
+        assert!(check_refund_open(finalized_time, refund_delay, finalized_time + refund_delay).is_ok());
+        // Without a delay refunds open on finalization
+        assert!(check_refund_open(finalized_time, 0, finalized_time).is_ok());
+    }
+
+    #[test]
+    fn token_allocation_at_maximal_inputs_fits() {
+        assert_eq!(calculate_user_token_allocation(u64::MAX, u64::MAX, u64::MAX).unwrap(), u64::MAX);
+        assert_eq!(calculate_user_token_allocation(1, u64::MAX, u64::MAX).unwrap(), 1);
+        assert_eq!(calculate_user_token_allocation(0, 0, u64::MAX).unwrap(), 0);
+    }
+
+    #[test]
+    fn token_allocation_beyond_u64_errors_instead_of_wrapping() {
+        // u64::MAX * u64::MAX / 1 would truncate to 1 under an `as u64` cast
+        assert!(calculate_user_token_allocation(u64::MAX, 1, u64::MAX).is_err());
+        assert!(calculate_user_token_allocation(2, 1, u64::MAX).is_err());
+    }
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::GLOBAL_CONFIG_SEED;
+use crate::errors::LaunchpadError;
+use crate::events::GlobalConfigClosed;
+use crate::state::{GlobalConfig, PlatformStats};
+
+#[derive(Accounts)]
+pub struct CloseConfig<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        close = admin,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Platform totals, which keep the launch pool index across reinitialization
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PlatformStats::SIZE,
+        seeds = [PlatformStats::SEED],
+        bump,
+    )]
+    pub platform_stats: Box<Account<'info, PlatformStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Close the global config and reclaim its rent so it can be reinitialized (admin only)
+/// Every launch pool created under the config must have gone through settle_launch_pool
+pub fn close_config(ctx: Context<CloseConfig>, confirm: bool) -> Result<()> {
+    require!(confirm, LaunchpadError::ConfirmationRequired);
+
+    let global_config = &ctx.accounts.global_config;
+    require!(global_config.active_pool_count == 0, LaunchpadError::LaunchesNotTerminal);
+
+    // The next config resumes at this index, so launch pool PDAs are never reused
+    let pool_count = global_config.pool_count;
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.bump = ctx.bumps.platform_stats;
+    platform_stats.next_pool_index = pool_count;
+
+    let clock = Clock::get()?;
+
+    emit!(GlobalConfigClosed {
+        admin: ctx.accounts.admin.key(),
+        pool_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Global config closed after {} launches", pool_count);
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LAUNCH_POOL_SEED, USER_POSITION_SEED};
+use crate::errors::LaunchpadError;
+use crate::events::UserPositionClosed;
+use crate::state::{LaunchPool, UserPosition};
+
+#[derive(Accounts)]
+pub struct CloseUserPosition<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Launch pool the position belongs to, must be terminal so the position cannot be recreated
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_terminal() @ LaunchpadError::InvalidStatus,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// User position to close, rent goes back to the user
+    #[account(
+        mut,
+        close = user,
+        seeds = [USER_POSITION_SEED, launch_pool.key().as_ref(), user.key().as_ref()],
+        bump = user_position.bump,
+        constraint = user_position.is_settled(launch_pool.excess_sol) @ LaunchpadError::PositionNotSettled,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+}
+
+/// Close a user position once tokens and excess SOL (or the refund) have been claimed
+pub fn close_user_position(ctx: Context<CloseUserPosition>) -> Result<()> {
+    let clock = Clock::get()?;
+    let rent_returned = ctx.accounts.user_position.to_account_info().lamports();
+
+    emit!(UserPositionClosed {
+        pool: ctx.accounts.launch_pool.key(),
+        user: ctx.accounts.user.key(),
+        rent_returned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Closed user position in pool {}", ctx.accounts.launch_pool.key());
+
+    Ok(())
+}
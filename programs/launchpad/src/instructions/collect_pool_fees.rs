@@ -5,7 +5,7 @@ use anchor_spl::{
     token_interface::{TokenAccount, TokenInterface},
 };
 
-use crate::{const_pda::const_authority::{POOL_ID, VAULT_BUMP}, constants::{GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED, VAULT_AUTHORITY}, cp_amm, errors::LaunchpadError, state::{GlobalConfig, LaunchPool}};
+use crate::{const_pda::const_authority::{vault_signer_seeds, POOL_ID}, constants::{CREATOR_FEE_ESCROW_SEED, GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED, VAULT_AUTHORITY}, cp_amm, errors::LaunchpadError, state::{GlobalConfig, LaunchPool}};
 
 #[derive(Accounts)]
 pub struct ClaimPositionFee<'info> {
@@ -45,9 +45,9 @@ pub struct ClaimPositionFee<'info> {
     #[account(address = global_config.admin.key())]
     pub treasury: UncheckedAccount<'info>,
 
-    /// Creator account from launch pool
-    /// CHECK: verified against launch_pool.creator
-    #[account(address = launch_pool.creator)]
+    /// Creator fee recipient (the creator unless an override is set)
+    /// CHECK: verified against launch_pool.creator_fee_recipient()
+    #[account(address = launch_pool.creator_fee_recipient())]
     pub creator: UncheckedAccount<'info>,
 
     /// CHECK: pool address
@@ -60,7 +60,7 @@ pub struct ClaimPositionFee<'info> {
     )]
     pub position: UncheckedAccount<'info>,
 
-    /// Treasury token a account
+    /// Treasury token a account, may be omitted when token a has no fees
     #[account(
         init_if_needed,
         payer = payer,
@@ -68,9 +68,9 @@ pub struct ClaimPositionFee<'info> {
         associated_token::authority = treasury,
         associated_token::token_program = token_a_program,
     )]
-    pub treasury_token_a_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub treasury_token_a_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
-    /// Treasury token b account
+    /// Treasury token b account, may be omitted when token b has no fees
     #[account(
         init_if_needed,
         payer = payer,
@@ -78,9 +78,9 @@ pub struct ClaimPositionFee<'info> {
         associated_token::authority = treasury,
         associated_token::token_program = token_b_program,
     )]
-    pub treasury_token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub treasury_token_b_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
-    /// Creator token a account
+    /// Creator token a account, required for fees on token a unless creator fees are escrowed
     #[account(
         init_if_needed,
         payer = payer,
@@ -88,9 +88,9 @@ pub struct ClaimPositionFee<'info> {
         associated_token::authority = creator,
         associated_token::token_program = token_a_program,
     )]
-    pub creator_token_a_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub creator_token_a_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
-    /// Creator token b account
+    /// Creator token b account, required for fees on token b unless creator fees are escrowed
     #[account(
         init_if_needed,
         payer = payer,
@@ -98,7 +98,31 @@ pub struct ClaimPositionFee<'info> {
         associated_token::authority = creator,
         associated_token::token_program = token_b_program,
     )]
-    pub creator_token_b_account: Box<InterfaceAccount<'info, TokenAccount>>,
+    pub creator_token_b_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Creator fee escrow for token a, required for fees on token a when creator fees are escrowed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [CREATOR_FEE_ESCROW_SEED, launch_pool.key().as_ref(), token_a_mint.key().as_ref()],
+        bump,
+        token::mint = token_a_mint,
+        token::authority = vault_authority,
+        token::token_program = token_a_program,
+    )]
+    pub creator_fee_escrow_a: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// Creator fee escrow for token b, required for fees on token b when creator fees are escrowed
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [CREATOR_FEE_ESCROW_SEED, launch_pool.key().as_ref(), token_b_mint.key().as_ref()],
+        bump,
+        token::mint = token_b_mint,
+        token::authority = vault_authority,
+        token::token_program = token_b_program,
+    )]
+    pub creator_fee_escrow_b: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
 
     /// Vault authority token a account (receives fees from AMM)
     #[account(
@@ -156,7 +180,7 @@ pub struct ClaimPositionFee<'info> {
 }
 
 impl<'info> ClaimPositionFee<'info> {
-    pub fn claim_position_fee(&mut self) -> Result<()> {
+    pub fn claim_position_fee(&mut self, vault_bump: u8) -> Result<()> {
         // Validate that the pool tokens match the launch pool tokens
         let token_a_mint = self.token_a_mint.key();
         let token_b_mint = self.token_b_mint.key();
@@ -174,7 +198,7 @@ impl<'info> ClaimPositionFee<'info> {
             LaunchpadError::InvalidTokenMint
         );
 
-        let vault_authority_seeds: &[&[u8]] = &[VAULT_AUTHORITY, &[VAULT_BUMP]];
+        let signer_seeds = vault_signer_seeds(vault_bump);
 
         // Step 1: Record the balances before claiming fees
         let token_a_before = self.vault_token_a_account.amount;
@@ -201,7 +225,7 @@ impl<'info> ClaimPositionFee<'info> {
                     event_authority: self.event_authority.to_account_info(),
                     program: self.amm_program.to_account_info(),
                 },
-                &[&vault_authority_seeds[..]],
+                signer_seeds,
             )
         )?;
 
@@ -216,78 +240,84 @@ impl<'info> ClaimPositionFee<'info> {
         let token_a_claimed = token_a_after.saturating_sub(token_a_before);
         let token_b_claimed = token_b_after.saturating_sub(token_b_before);
 
-        // Step 5: Calculate 50% of claimed fees for distribution
-        let token_a_half = token_a_claimed / 2;
-        let token_b_half = token_b_claimed / 2;
+        // Step 5: Split claimed fees by the configured creator share
+        let (token_a_treasury, token_a_creator) = self.global_config.split_pool_fee(token_a_claimed)?;
+        let (token_b_treasury, token_b_creator) = self.global_config.split_pool_fee(token_b_claimed)?;
 
-        // Step 6: Transfer 50% of token_a to treasury
-        if token_a_half > 0 {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    self.token_program.to_account_info(),
-                    Transfer {
-                        from: self.vault_token_a_account.to_account_info(),
-                        to: self.treasury_token_a_account.to_account_info(),
-                        authority: self.vault_authority.to_account_info(),
-                    },
-                    &[&vault_authority_seeds[..]],
-                ),
-                token_a_half,
-            )?;
-        }
+        // Step 6: Route the creator's share to escrow or directly to the creator
+        let (creator_a_destination, creator_b_destination) = if self.launch_pool.escrow_creator_fees {
+            (
+                self.creator_fee_escrow_a.as_ref().map(|account| account.to_account_info()),
+                self.creator_fee_escrow_b.as_ref().map(|account| account.to_account_info()),
+            )
+        } else {
+            (
+                self.creator_token_a_account.as_ref().map(|account| account.to_account_info()),
+                self.creator_token_b_account.as_ref().map(|account| account.to_account_info()),
+            )
+        };
+
+        // Step 7: Split token_a between treasury and creator
+        // Destination accounts that receive nothing may be omitted
+        self.distribute_fee_side(
+            self.vault_token_a_account.to_account_info(),
+            self.treasury_token_a_account.as_ref().map(|account| account.to_account_info()),
+            creator_a_destination,
+            token_a_treasury,
+            token_a_creator,
+            signer_seeds,
+        )?;
 
-        // Step 7: Transfer 50% of token_a to creator
-        if token_a_half > 0 {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    self.token_program.to_account_info(),
-                    Transfer {
-                        from: self.vault_token_a_account.to_account_info(),
-                        to: self.creator_token_a_account.to_account_info(),
-                        authority: self.vault_authority.to_account_info(),
-                    },
-                    &[&vault_authority_seeds[..]],
-                ),
-                token_a_claimed.checked_sub(token_a_half).ok_or(LaunchpadError::MathOverflow)?,
-            )?;
-        }
+        // Step 8: Split token_b between treasury and creator
+        self.distribute_fee_side(
+            self.vault_token_b_account.to_account_info(),
+            self.treasury_token_b_account.as_ref().map(|account| account.to_account_info()),
+            creator_b_destination,
+            token_b_treasury,
+            token_b_creator,
+            signer_seeds,
+        )?;
 
-        // Step 8: Transfer 50% of token_b to treasury
-        if token_b_half > 0 {
-            token::transfer(
-                CpiContext::new_with_signer(
-                    self.token_program.to_account_info(),
-                    Transfer {
-                        from: self.vault_token_b_account.to_account_info(),
-                        to: self.treasury_token_b_account.to_account_info(),
-                        authority: self.vault_authority.to_account_info(),
-                    },
-                    &[&vault_authority_seeds[..]],
-                ),
-                token_b_claimed.checked_sub(token_b_half).ok_or(LaunchpadError::MathOverflow)?,
-            )?;
-        }
+        msg!("Fees claimed and distributed successfully");
+        msg!("Token A claimed: {}, distributed: {} to treasury, {} to creator", token_a_claimed, token_a_treasury, token_a_creator);
+        msg!("Token B claimed: {}, distributed: {} to treasury, {} to creator", token_b_claimed, token_b_treasury, token_b_creator);
+
+        Ok(())
+    }
+
+    /// Transfer one token side's fees from the vault to the treasury and creator destinations
+    /// Zero amounts are skipped, so their destination may be omitted
+    fn distribute_fee_side(
+        &self,
+        from: AccountInfo<'info>,
+        treasury_destination: Option<AccountInfo<'info>>,
+        creator_destination: Option<AccountInfo<'info>>,
+        treasury_amount: u64,
+        creator_amount: u64,
+        signer_seeds: &[&[&[u8]]],
+    ) -> Result<()> {
+        for (destination, amount) in [
+            (treasury_destination, treasury_amount),
+            (creator_destination, creator_amount),
+        ] {
+            if amount == 0 {
+                continue;
+            }
 
-        // Step 9: Transfer 50% of token_b to creator
-        if token_b_half > 0 {
             token::transfer(
                 CpiContext::new_with_signer(
                     self.token_program.to_account_info(),
                     Transfer {
-                        from: self.vault_token_b_account.to_account_info(),
-                        to: self.creator_token_b_account.to_account_info(),
+                        from: from.clone(),
+                        to: destination.ok_or(LaunchpadError::MissingFeeAccount)?,
                         authority: self.vault_authority.to_account_info(),
                     },
-                    &[&vault_authority_seeds[..]],
+                    signer_seeds,
                 ),
-                token_b_half,
+                amount,
             )?;
         }
 
-        msg!("Fees claimed and distributed successfully");
-        msg!("Token A claimed: {}, distributed: {} to treasury, {} to creator", token_a_claimed, token_a_half, token_a_half);
-        msg!("Token B claimed: {}, distributed: {} to treasury, {} to creator", token_b_claimed, token_b_half, token_b_half);
-
         Ok(())
     }
 }
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::cp_amm;
+use crate::errors::LaunchpadError;
+use crate::events::LiquidityLocked;
+use crate::instructions::apply_finalization;
+use crate::instructions::meteora_pool::*;
+use crate::utils::validation::check_can_finalize;
+
+/// Reuses the create_meteora_pool accounts; the launch pool is finalized in the same instruction
+#[derive(Accounts)]
+pub struct CompleteLaunch<'info> {
+    pub damm: DammV2<'info>,
+}
+
+/// Finalize a successful launch, create its Meteora pool and optionally lock extra liquidity, atomically
+/// If any step fails the whole instruction reverts. Launches whose migration does not fit in one
+/// transaction's compute budget should fall back to finalize_launch, create_meteora_pool and lock_liquidity.
+pub fn complete_launch(ctx: Context<CompleteLaunch>, extra_lock_amount: u128) -> Result<()> {
+    let vault_bump = ctx.bumps.damm.vault_authority;
+    let stats_bump = ctx.bumps.damm.platform_stats;
+    let damm = &mut ctx.accounts.damm;
+    let clock = Clock::get()?;
+
+    // Step 1: finalize, which must end in Success
+    if damm.launch_pool.is_active() {
+        check_can_finalize(&damm.launch_pool, clock.unix_timestamp)?;
+        let pool_key = damm.launch_pool.key();
+        apply_finalization(&mut damm.launch_pool, &mut damm.platform_stats, pool_key, clock.unix_timestamp)?;
+    }
+    require!(damm.launch_pool.is_success(), LaunchpadError::LaunchFailed);
+
+    // Step 2: migrate into Meteora, which already permanently locks the launch's permanent_lock_bps share
+    damm.create_pool(vault_bump, stats_bump)?;
+
+    // Step 3: lock additional liquidity (admin only)
+    if extra_lock_amount > 0 {
+        require_keys_eq!(damm.payer.key(), damm.global_config.admin, LaunchpadError::Unauthorized);
+
+        cp_amm::cpi::permanent_lock_position(
+            CpiContext::new_with_signer(
+                damm.amm_program.to_account_info(),
+                cp_amm::cpi::accounts::PermanentLockPosition {
+                    pool: damm.pool.to_account_info(),
+                    position: damm.position.to_account_info(),
+                    position_nft_account: damm.position_nft_account.to_account_info(),
+                    owner: damm.vault_authority.to_account_info(),
+                    event_authority: damm.damm_event_authority.to_account_info(),
+                    program: damm.amm_program.to_account_info(),
+                },
+                vault_signer_seeds(vault_bump),
+            ),
+            extra_lock_amount,
+        )?;
+
+        emit!(LiquidityLocked {
+            launch_pool: damm.launch_pool.key(),
+            position: damm.position.key(),
+            pool: damm.pool.key(),
+            locked_amount: extra_lock_amount,
+            admin: damm.payer.key(),
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Locked an additional {} liquidity units", extra_lock_amount);
+    }
+
+    msg!("Launch completed in a single instruction");
+
+    Ok(())
+}
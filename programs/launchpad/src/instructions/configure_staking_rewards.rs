@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+use crate::constants::VAULT_AUTHORITY;
+use crate::errors::LaunchpadError;
+use crate::events::StakingRewardsConfigured;
+use crate::state::{GlobalConfig, StakingRewardConfig};
+
+#[derive(Accounts)]
+pub struct ConfigureStakingRewards<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Token mint of the staked token
+    pub token_mint: Account<'info, Mint>,
+
+    /// Token mint the rewards are paid in
+    pub reward_mint: Account<'info, Mint>,
+
+    /// Reward accumulator for the staked token
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = StakingRewardConfig::SIZE,
+        seeds = [StakingRewardConfig::SEED, token_mint.key().as_ref()],
+        bump,
+    )]
+    pub staking_reward_config: Account<'info, StakingRewardConfig>,
+
+    /// Vault the treasury funds rewards into, separate from the staked token vault
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [StakingRewardConfig::VAULT_SEED, staking_reward_config.key().as_ref()],
+        bump,
+        token::mint = reward_mint,
+        token::authority = vault_authority,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Create a token's staking reward stream or change its rate (admin only)
+/// Rewards accrued at the previous rate are settled into the accumulator first
+pub fn configure_staking_rewards(ctx: Context<ConfigureStakingRewards>, reward_rate: u64) -> Result<()> {
+    let config = &mut ctx.accounts.staking_reward_config;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    if config.token_mint == Pubkey::default() {
+        config.token_mint = ctx.accounts.token_mint.key();
+        config.reward_mint = ctx.accounts.reward_mint.key();
+        config.last_update_ts = current_time;
+        config.bump = ctx.bumps.staking_reward_config;
+    }
+    require_keys_eq!(config.reward_mint, ctx.accounts.reward_mint.key(), LaunchpadError::InvalidTokenMint);
+
+    config.update_rewards(current_time)?;
+    let previous_reward_rate = config.reward_rate;
+    config.reward_rate = reward_rate;
+
+    emit!(StakingRewardsConfigured {
+        config: config.key(),
+        token_mint: config.token_mint,
+        reward_mint: config.reward_mint,
+        previous_reward_rate,
+        reward_rate,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Staking rewards for {} set to {} per second (was {})",
+        config.token_mint,
+        reward_rate,
+        previous_reward_rate
+    );
+
+    Ok(())
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::ExcessConvertedToDividends;
+use crate::state::{GlobalConfig, LaunchPool};
+
+#[derive(Accounts)]
+pub struct ConvertExcessToDividends<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// CHECK: WSOL mint (verified by address)
+    #[account(
+        address = anchor_spl::token::spl_token::native_mint::ID
+    )]
+    pub wsol_mint: Account<'info, Mint>,
+
+    /// Launch pool account, must be migrated
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_migrated() @ LaunchpadError::NotMigrated,
+        constraint = !launch_pool.excess_converted @ LaunchpadError::ExcessConverted,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// Pool's quote vault (SOL)
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = vault_authority,
+        address = launch_pool.quote_vault @ LaunchpadError::InvalidQuoteVault,
+    )]
+    pub pool_quote_vault: Box<Account<'info, TokenAccount>>,
+
+    /// SOL dividend vault keyed by the launch's token mint
+    #[account(
+        init_if_needed,
+        payer = admin,
+        seeds = [SOL_DIVIDEND_SEED, launch_pool.token_mint.as_ref()],
+        bump,
+        token::mint = wsol_mint,
+        token::authority = vault_authority,
+        token::token_program = token_program
+    )]
+    pub sol_dividend_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Move a migrated pool's undistributed excess SOL into the SOL dividend vault for its token mint
+/// Participants then claim through claim_sol_dividends with points_signer signed totals
+pub fn convert_excess_to_dividends(ctx: Context<ConvertExcessToDividends>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let clock = Clock::get()?;
+
+    let amount = launch_pool.excess_sol
+        .checked_sub(launch_pool.excess_sol_distributed)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    require!(amount > 0, LaunchpadError::NothingToClaim);
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_quote_vault.to_account_info(),
+                to: ctx.accounts.sol_dividend_vault.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            vault_signer_seeds(ctx.bumps.vault_authority),
+        ),
+        amount,
+    )?;
+
+    // Per-user excess refunds are disabled from here on
+    launch_pool.excess_converted = true;
+
+    emit!(ExcessConvertedToDividends {
+        pool: launch_pool.key(),
+        token_mint: launch_pool.token_mint,
+        amount,
+        already_distributed: launch_pool.excess_sol_distributed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Converted {} excess SOL of pool {} into dividends", amount, launch_pool.key());
+
+    Ok(())
+}
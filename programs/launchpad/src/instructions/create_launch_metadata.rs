@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{CreateMetadataAccountsV3, Metadata};
+use anchor_spl::token_interface::spl_token_2022::instruction::AuthorityType;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+
+use crate::constants::{GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED};
+use crate::errors::LaunchpadError;
+use crate::state::{GlobalConfig, LaunchPool, SymbolRegistry};
+use crate::utils::token::{create_launch_token_metadata, revoke_launch_mint_authority};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateLaunchMetadataParams {
+    pub token_name: String,
+    pub token_symbol: String,
+    pub token_uri: String,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CreateLaunchMetadataParams)]
+pub struct CreateLaunchMetadata<'info> {
+    /// Launch creator, pays for the metadata account
+    #[account(
+        mut,
+        address = launch_pool.creator @ LaunchpadError::NotCreator,
+    )]
+    pub creator: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Launch pool, still the mint authority of its token and not failed, as failed launches release their symbol
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = !launch_pool.is_failed() @ LaunchpadError::InvalidStatus,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// Launch token mint
+    #[account(
+        mut,
+        address = launch_pool.token_mint @ LaunchpadError::InvalidTokenMint,
+        mint::token_program = token_program,
+        constraint = token_mint.mint_authority == Some(launch_pool.key()).into() @ LaunchpadError::MintAuthorityRevoked,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Symbol registered by this launch, required when symbol uniqueness is enforced
+    #[account(
+        seeds = [SymbolRegistry::SEED, &SymbolRegistry::hash_symbol(&params.token_symbol)],
+        bump = symbol_registry.bump,
+        constraint = symbol_registry.launch_pool == launch_pool.key() @ LaunchpadError::DuplicateSymbol,
+    )]
+    pub symbol_registry: Option<Box<Account<'info, SymbolRegistry>>>,
+
+    /// CHECK: Validated by Metaplex program
+    #[account(
+        mut,
+        seeds = [
+            b"metadata",
+            mpl_token_metadata::ID.as_ref(),
+            token_mint.key().as_ref(),
+        ],
+        seeds::program = mpl_token_metadata::ID,
+        bump,
+    )]
+    pub metadata: UncheckedAccount<'info>,
+
+    /// Token program of the launch token, classic SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Metadata program
+    pub metadata_program: Program<'info, Metadata>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+
+    /// Rent
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Create the Metaplex metadata of a launch that skipped it at initialization, then revoke the mint authority (creator only)
+pub fn create_launch_metadata(
+    ctx: Context<CreateLaunchMetadata>,
+    params: CreateLaunchMetadataParams,
+) -> Result<()> {
+    // The symbol must be the one this launch reserved, not a fresh one that skips the uniqueness check
+    if ctx.accounts.global_config.enforce_unique_symbols {
+        require!(ctx.accounts.symbol_registry.is_some(), LaunchpadError::DuplicateSymbol);
+    }
+
+    let launch_pool = &ctx.accounts.launch_pool;
+    let index_bytes = launch_pool.index.to_le_bytes();
+    let seeds = &[
+        LAUNCH_POOL_SEED,
+        launch_pool.creator.as_ref(),
+        &index_bytes,
+        &[launch_pool.bump],
+    ];
+    let signer_seeds = &[&seeds[..]];
+
+    let metadata_accounts = CreateMetadataAccountsV3 {
+        metadata: ctx.accounts.metadata.to_account_info(),
+        mint: ctx.accounts.token_mint.to_account_info(),
+        mint_authority: launch_pool.to_account_info(),
+        payer: ctx.accounts.creator.to_account_info(),
+        update_authority: launch_pool.to_account_info(),
+        system_program: ctx.accounts.system_program.to_account_info(),
+        rent: ctx.accounts.rent.to_account_info(),
+    };
+
+    create_launch_token_metadata(
+        ctx.accounts.metadata_program.to_account_info(),
+        metadata_accounts,
+        params.token_name,
+        params.token_symbol,
+        params.token_uri,
+        signer_seeds,
+    )?;
+
+    revoke_launch_mint_authority(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        launch_pool.to_account_info(),
+        AuthorityType::MintTokens,
+        signer_seeds,
+    )?;
+
+    msg!("Created metadata and revoked mint authority for {}", ctx.accounts.token_mint.key());
+
+    Ok(())
+}
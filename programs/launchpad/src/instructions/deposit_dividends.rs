@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::constants::{TOKEN_VAULT, VAULT_AUTHORITY};
+use crate::errors::LaunchpadError;
+use crate::events::DividendsDeposited;
+use crate::state::DividendPool;
+
+#[derive(Accounts)]
+pub struct DepositDividends<'info> {
+    /// Account funding the dividends
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Token mint of the dividends
+    pub token_mint: Account<'info, Mint>,
+
+    /// Depositor's token account (source of the dividends)
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = depositor,
+    )]
+    pub depositor_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token vault claim_token_dividends pays out from
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        seeds = [TOKEN_VAULT, vault_authority.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = vault_authority,
+    )]
+    pub dividend_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Running dividend totals for this token mint
+    #[account(
+        init_if_needed,
+        payer = depositor,
+        space = DividendPool::SIZE,
+        seeds = [DividendPool::SEED, token_mint.key().as_ref()],
+        bump,
+    )]
+    pub dividend_pool: Box<Account<'info, DividendPool>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Fund token dividends for a mint, recording the deposit so funded and claimed amounts can be reconciled
+pub fn deposit_dividends(ctx: Context<DepositDividends>, amount: u64) -> Result<()> {
+    require!(amount > 0, LaunchpadError::InvalidAmount);
+
+    let dividend_pool = &mut ctx.accounts.dividend_pool;
+    if dividend_pool.token_mint == Pubkey::default() {
+        dividend_pool.token_mint = ctx.accounts.token_mint.key();
+        dividend_pool.bump = ctx.bumps.dividend_pool;
+    }
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.depositor_token_account.to_account_info(),
+                to: ctx.accounts.dividend_vault.to_account_info(),
+                authority: ctx.accounts.depositor.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    dividend_pool.record_deposit(amount)?;
+
+    let clock = Clock::get()?;
+
+    emit!(DividendsDeposited {
+        depositor: ctx.accounts.depositor.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        amount,
+        total_deposited: dividend_pool.total_deposited,
+        total_distributed: dividend_pool.total_distributed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Deposited {} dividend tokens of mint {}", amount, ctx.accounts.token_mint.key());
+
+    Ok(())
+}
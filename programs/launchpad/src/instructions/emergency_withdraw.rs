@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::{GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED, VAULT_AUTHORITY};
+use crate::errors::LaunchpadError;
+use crate::events::EmergencyWithdraw as EmergencyWithdrawEvent;
+use crate::state::{GlobalConfig, LaunchPool};
+
+#[derive(Accounts)]
+pub struct EmergencyWithdraw<'info> {
+    #[account(
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account, the platform must be paused
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        constraint = global_config.paused @ LaunchpadError::PlatformNotPaused,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Launch pool the vault belongs to, omitted for vaults not tied to a launch
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Option<Box<Account<'info, LaunchPool>>>,
+
+    /// Mint of the stranded tokens
+    #[account(mint::token_program = token_program)]
+    pub mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Vault-authority-owned token account to recover funds from
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = vault_authority,
+        token::token_program = token_program,
+    )]
+    pub source: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Admin token account receiving the funds
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = global_config.admin,
+        token::token_program = token_program,
+    )]
+    pub destination: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Move stranded funds out of a vault-authority token account to the admin (admin only, platform paused)
+/// Meant for recovering from half-failed migrations, it bypasses every per-launch accounting field
+pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, amount: u64) -> Result<()> {
+    require!(amount > 0, LaunchpadError::InvalidAmount);
+    require!(
+        ctx.accounts.source.amount >= amount,
+        LaunchpadError::InsufficientVaultBalance
+    );
+
+    token_interface::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.source.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            vault_signer_seeds(ctx.bumps.vault_authority),
+        ),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+
+    let pool = ctx.accounts.launch_pool.as_ref().map(|launch_pool| launch_pool.key());
+    let clock = Clock::get()?;
+
+    emit!(EmergencyWithdrawEvent {
+        admin: ctx.accounts.admin.key(),
+        pool,
+        source: ctx.accounts.source.key(),
+        destination: ctx.accounts.destination.key(),
+        mint: ctx.accounts.mint.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Emergency withdrew {} of mint {} from {} (pool {:?})",
+        amount,
+        ctx.accounts.mint.key(),
+        ctx.accounts.source.key(),
+        pool
+    );
+
+    Ok(())
+}
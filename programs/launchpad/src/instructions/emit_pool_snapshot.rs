@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LAUNCH_POOL_SEED;
+use crate::state::LaunchPool;
+use crate::events::PoolSnapshot;
+
+#[derive(Accounts)]
+pub struct EmitPoolSnapshot<'info> {
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+}
+
+/// Emit the current fundraising progress of a pool (permissionless, read-only)
+/// Keepers can call this periodically so indexers can reconcile missed participation events
+pub fn emit_pool_snapshot(ctx: Context<EmitPoolSnapshot>) -> Result<()> {
+    let launch_pool = &ctx.accounts.launch_pool;
+    let clock = Clock::get()?;
+
+    emit!(PoolSnapshot {
+        pool: launch_pool.key(),
+        status: launch_pool.status as u8,
+        raised_sol: launch_pool.raised_sol,
+        participants_count: launch_pool.participants_count,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::LaunchpadError;
+use crate::events::StakeLockExtended;
+use crate::state::{GlobalConfig, StakingPosition};
+
+#[derive(Accounts)]
+#[instruction(position_index: u64)]
+pub struct ExtendLock<'info> {
+    /// Owner of the staking position
+    pub user: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// Token mint of the staked token
+    pub token_mint: Account<'info, Mint>,
+
+    /// Staking position to extend
+    #[account(
+        mut,
+        seeds = [
+            StakingPosition::SEED,
+            user.key().as_ref(),
+            token_mint.key().as_ref(),
+            &position_index.to_le_bytes()
+        ],
+        bump = staking_position.bump,
+        constraint = staking_position.user == user.key() @ LaunchpadError::NoStakeFound,
+        constraint = staking_position.token_mint == token_mint.key() @ LaunchpadError::InvalidStakingTokenMint,
+    )]
+    pub staking_position: Account<'info, StakingPosition>,
+}
+
+/// Extend a staking position's lock by `additional_duration` seconds without restaking
+/// The resulting lock duration must still satisfy the staking parameters
+pub fn extend_lock(ctx: Context<ExtendLock>, _position_index: u64, additional_duration: i64) -> Result<()> {
+    let staking_position = &mut ctx.accounts.staking_position;
+    let current_time = Clock::get()?.unix_timestamp;
+    let old_unlock_time = staking_position.unlock_time;
+
+    staking_position.extend_lock(additional_duration)?;
+    ctx.accounts.global_config.validate_stake_params(staking_position.lock_duration)?;
+
+    emit!(StakeLockExtended {
+        user: ctx.accounts.user.key(),
+        position: staking_position.key(),
+        token_mint: staking_position.token_mint,
+        old_unlock_time,
+        new_unlock_time: staking_position.unlock_time,
+        lock_duration: staking_position.lock_duration,
+        timestamp: current_time,
+    });
+
+    msg!(
+        "Staking position of {} extended, unlocks at {} instead of {}",
+        ctx.accounts.user.key(),
+        staking_position.unlock_time,
+        old_unlock_time
+    );
+
+    Ok(())
+}
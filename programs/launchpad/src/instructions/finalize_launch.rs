@@ -1,15 +1,21 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
 
-use crate::constants::LAUNCH_POOL_SEED;
+use crate::constants::{GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED};
 use crate::errors::LaunchpadError;
-use crate::state::{LaunchPool, LaunchStatus};
+use crate::instructions::close_symbol_registry;
+use crate::state::{GlobalConfig, LaunchPool, LaunchStatus, PlatformStats, SymbolRegistry};
+use crate::utils::settle_creator_bond;
 use crate::utils::validation::check_can_finalize;
-use crate::events::{LaunchFinalized, LaunchStatusChanged};
+use crate::events::{LaunchExtended, LaunchFinalized, LaunchStatusChanged};
 
 #[derive(Accounts)]
 pub struct FinalizeLaunch<'info> {
-    #[account(mut)]
+    /// Launch creator or admin
+    #[account(
+        mut,
+        constraint = authority.key() == launch_pool.creator || authority.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
     pub authority: Signer<'info>,
 
     #[account(
@@ -19,6 +25,46 @@ pub struct FinalizeLaunch<'info> {
         constraint = launch_pool.is_active() @ LaunchpadError::LaunchNotActive,
     )]
     pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Treasury receiving slashed creator bonds
+    #[account(
+        mut,
+        address = global_config.admin @ LaunchpadError::Unauthorized,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Platform-wide launch totals
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PlatformStats::SIZE,
+        seeds = [PlatformStats::SEED],
+        bump,
+    )]
+    pub platform_stats: Box<Account<'info, PlatformStats>>,
+
+    /// Symbol registry held by the launch, closed if the launch fails
+    #[account(
+        mut,
+        constraint = symbol_registry.launch_pool == launch_pool.key() @ LaunchpadError::InvalidLaunchPool,
+    )]
+    pub symbol_registry: Option<Box<Account<'info, SymbolRegistry>>>,
+
+    /// Launch creator, receives the symbol registry rent
+    #[account(
+        mut,
+        address = launch_pool.creator @ LaunchpadError::NotCreator,
+    )]
+    pub creator: Option<SystemAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn finalize_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
@@ -28,10 +74,55 @@ pub fn finalize_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
     // Validate if can finalize
     check_can_finalize(launch_pool, clock.unix_timestamp)?;
 
+    let pool_key = launch_pool.key();
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.bump = ctx.bumps.platform_stats;
+    apply_finalization(launch_pool, platform_stats, pool_key, clock.unix_timestamp)?;
+
+    // Slash the creator bond if the launch failed
+    if launch_pool.is_failed() {
+        settle_creator_bond(launch_pool, &ctx.accounts.treasury.to_account_info(), true)?;
+
+        // Free the symbol for later launches
+        if let Some(symbol_registry) = &ctx.accounts.symbol_registry {
+            let creator = ctx.accounts.creator.as_ref().ok_or(LaunchpadError::CreatorAccountRequired)?;
+            close_symbol_registry(symbol_registry, &creator.to_account_info())?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Move an eligible pool to Success or Failed and emit the lifecycle events
+/// A pool that narrowly missed its target is extended instead while extensions remain
+pub fn apply_finalization(
+    launch_pool: &mut LaunchPool,
+    platform_stats: &mut PlatformStats,
+    pool_key: Pubkey,
+    current_time: i64,
+) -> Result<()> {
+    if launch_pool.can_extend_near_miss(current_time) {
+        let previous_end_time = launch_pool.end_time;
+        launch_pool.extend_end_time()?;
+
+        emit!(LaunchExtended {
+            pool: pool_key,
+            previous_end_time,
+            new_end_time: launch_pool.end_time,
+            raised_amount: launch_pool.raised_sol,
+            target_amount: launch_pool.target_sol,
+            extensions_used: launch_pool.extensions_used,
+            timestamp: current_time,
+        });
+
+        msg!("Launch narrowly missed target, extended to {}", launch_pool.end_time);
+        return Ok(());
+    }
+
     let previous_status = launch_pool.status as u8;
 
-    // Check if target is reached
-    let success = launch_pool.raised_sol >= launch_pool.target_sol;
+    // Check if the soft cap is reached by enough participants, target_sol only caps liquidity
+    let success = launch_pool.meets_success_criteria();
 
     if success {
         // Success - mark as successful status, waiting for subsequent create_meteora_pool call
@@ -51,21 +142,22 @@ pub fn finalize_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
         );
     }
 
-    launch_pool.finalized_time = clock.unix_timestamp;
+    launch_pool.finalized_time = current_time;
+    platform_stats.record_finalization(success)?;
 
     // Emit status change event
     emit!(LaunchStatusChanged {
-        pool: launch_pool.key(),
+        pool: pool_key,
         previous_status,
         new_status: launch_pool.status as u8,
         raised_amount: launch_pool.raised_sol,
         target_amount: launch_pool.target_sol,
-        timestamp: clock.unix_timestamp,
+        timestamp: current_time,
     });
 
     // Emit launch finalized event
     emit!(LaunchFinalized {
-        pool: launch_pool.key(),
+        pool: pool_key,
         creator: launch_pool.creator,
         success,
         raised_amount: launch_pool.raised_sol,
@@ -74,7 +166,7 @@ pub fn finalize_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
         excess_amount: launch_pool.excess_sol,
         participants_count: launch_pool.participants_count,
         total_points_consumed: launch_pool.total_points_consumed,
-        timestamp: clock.unix_timestamp,
+        timestamp: current_time,
     });
 
     Ok(())
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GLOBAL_CONFIG_SEED, MAX_FINALIZE_BATCH_SIZE};
+use crate::errors::LaunchpadError;
+use crate::instructions::apply_finalization;
+use crate::state::{GlobalConfig, LaunchPool, PlatformStats};
+use crate::utils::settle_creator_bond;
+use crate::utils::validation::check_can_finalize;
+
+/// Launch pools to finalize are passed as writable remaining accounts
+#[derive(Accounts)]
+pub struct FinalizeLaunchBatch<'info> {
+    /// Admin, since the batch spans launches of different creators
+    #[account(
+        mut,
+        constraint = authority.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Treasury receiving slashed creator bonds
+    #[account(
+        mut,
+        address = global_config.admin @ LaunchpadError::Unauthorized,
+    )]
+    pub treasury: SystemAccount<'info>,
+
+    /// Platform-wide launch totals
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = PlatformStats::SIZE,
+        seeds = [PlatformStats::SEED],
+        bump,
+    )]
+    pub platform_stats: Box<Account<'info, PlatformStats>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Finalize every eligible pool in the batch, skipping pools that cannot be finalized yet
+pub fn finalize_launch_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeLaunchBatch<'info>>,
+) -> Result<()> {
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= MAX_FINALIZE_BATCH_SIZE,
+        LaunchpadError::InvalidBatchSize
+    );
+
+    let clock = Clock::get()?;
+    ctx.accounts.platform_stats.bump = ctx.bumps.platform_stats;
+    let mut finalized_count: u32 = 0;
+
+    for account_info in ctx.remaining_accounts.iter() {
+        require!(account_info.is_writable, LaunchpadError::InvalidLaunchPool);
+
+        let mut launch_pool = LaunchPool::load_canonical(account_info)?;
+
+        if check_can_finalize(&launch_pool, clock.unix_timestamp).is_err() {
+            msg!("Skipping pool {}: not eligible for finalization", account_info.key());
+            continue;
+        }
+
+        apply_finalization(
+            &mut launch_pool,
+            &mut ctx.accounts.platform_stats,
+            account_info.key(),
+            clock.unix_timestamp,
+        )?;
+        if launch_pool.is_failed() {
+            settle_creator_bond(&mut launch_pool, &ctx.accounts.treasury.to_account_info(), true)?;
+        }
+        launch_pool.exit(&crate::ID)?;
+        finalized_count += 1;
+    }
+
+    msg!("Finalized {} of {} pools", finalized_count, ctx.remaining_accounts.len());
+
+    Ok(())
+}
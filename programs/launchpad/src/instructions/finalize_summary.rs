@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LAUNCH_POOL_SEED;
+use crate::errors::LaunchpadError;
+use crate::events::LaunchSummarized;
+use crate::state::{LaunchPool, LaunchSummary};
+
+#[derive(Accounts)]
+pub struct FinalizeSummary<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// Launch pool, must be failed or migrated
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_terminal() @ LaunchpadError::InvalidLaunchStatus,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// Summary record, written once per launch
+    #[account(
+        init,
+        payer = payer,
+        space = LaunchSummary::SIZE,
+        seeds = [LaunchSummary::SEED, launch_pool.key().as_ref()],
+        bump,
+    )]
+    pub launch_summary: Box<Account<'info, LaunchSummary>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Write the terminal financial summary of a launch (permissionless, once per launch)
+/// Figures are copied from the pool's recorded state; excess_distributed reflects claims made so far.
+/// Pool fees and burns are not tracked on the launch pool and are therefore not part of the summary.
+pub fn finalize_summary(ctx: Context<FinalizeSummary>) -> Result<()> {
+    let launch_pool = &ctx.accounts.launch_pool;
+    let launch_summary = &mut ctx.accounts.launch_summary;
+    let clock = Clock::get()?;
+
+    launch_summary.launch_pool = launch_pool.key();
+    launch_summary.token_mint = launch_pool.token_mint;
+    launch_summary.creator = launch_pool.creator;
+    launch_summary.status = launch_pool.status as u8;
+    launch_summary.target_sol = launch_pool.target_sol;
+    launch_summary.raised_sol = launch_pool.raised_sol;
+    launch_summary.liquidity_sol = launch_pool.liquidity_sol;
+    launch_summary.excess_sol = launch_pool.excess_sol;
+    launch_summary.excess_distributed = launch_pool.excess_sol_distributed;
+    launch_summary.excess_converted = launch_pool.excess_converted;
+    launch_summary.liquidity_allocation = launch_pool.liquidity_allocation;
+    launch_summary.sale_allocation = launch_pool.sale_allocation;
+    launch_summary.creator_allocation = launch_pool.creator_allocation;
+    launch_summary.participants_count = launch_pool.participants_count;
+    launch_summary.total_points_consumed = launch_pool.total_points_consumed;
+    launch_summary.finalized_time = launch_pool.finalized_time;
+    launch_summary.summarized_at = clock.unix_timestamp;
+    launch_summary.bump = ctx.bumps.launch_summary;
+
+    emit!(LaunchSummarized {
+        pool: launch_pool.key(),
+        summary: launch_summary.key(),
+        status: launch_summary.status,
+        raised_sol: launch_summary.raised_sol,
+        liquidity_sol: launch_summary.liquidity_sol,
+        excess_sol: launch_summary.excess_sol,
+        excess_distributed: launch_summary.excess_distributed,
+        creator_allocation: launch_summary.creator_allocation,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Launch summary written for pool {}", launch_pool.key());
+
+    Ok(())
+}
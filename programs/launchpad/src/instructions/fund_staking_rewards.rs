@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+
+use crate::errors::LaunchpadError;
+use crate::events::StakingRewardsFunded;
+use crate::state::StakingRewardConfig;
+
+#[derive(Accounts)]
+pub struct FundStakingRewards<'info> {
+    /// Account funding the rewards
+    pub funder: Signer<'info>,
+
+    /// Reward accumulator being funded
+    #[account(
+        mut,
+        seeds = [StakingRewardConfig::SEED, staking_reward_config.token_mint.as_ref()],
+        bump = staking_reward_config.bump,
+    )]
+    pub staking_reward_config: Account<'info, StakingRewardConfig>,
+
+    /// Funder's token account (source of the rewards)
+    #[account(
+        mut,
+        token::mint = staking_reward_config.reward_mint,
+        token::authority = funder,
+    )]
+    pub funder_token_account: Account<'info, TokenAccount>,
+
+    /// Reward vault the stream pays out from
+    #[account(
+        mut,
+        seeds = [StakingRewardConfig::VAULT_SEED, staking_reward_config.key().as_ref()],
+        bump,
+        token::mint = staking_reward_config.reward_mint,
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Fund a token's staking reward stream
+/// Only rewards funded through here are streamed, direct transfers into the vault are not budgeted
+pub fn fund_staking_rewards(ctx: Context<FundStakingRewards>, amount: u64) -> Result<()> {
+    require!(amount > 0, LaunchpadError::InvalidAmount);
+
+    let current_time = Clock::get()?.unix_timestamp;
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder_token_account.to_account_info(),
+                to: ctx.accounts.reward_vault.to_account_info(),
+                authority: ctx.accounts.funder.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let config = &mut ctx.accounts.staking_reward_config;
+    config.fund(amount, current_time)?;
+
+    emit!(StakingRewardsFunded {
+        config: config.key(),
+        funder: ctx.accounts.funder.key(),
+        amount,
+        reward_budget: config.reward_budget,
+        timestamp: current_time,
+    });
+
+    msg!("Funded {} staking rewards for {}, budget now {}", amount, config.token_mint, config.reward_budget);
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LAUNCH_POOL_SEED;
+use crate::state::LaunchPool;
+
+/// Creator vesting status returned by get_creator_vesting
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreatorVestingView {
+    /// Time the cliff lock ends (linear unlock starts)
+    pub lock_end_time: i64,
+    /// Time the linear unlock completes
+    pub unlock_end_time: i64,
+    /// Creator tokens claimable now
+    pub claimable_amount: u64,
+    /// Whether the creator tokens are still in the cliff lock
+    pub is_locked: bool,
+}
+
+#[derive(Accounts)]
+pub struct GetCreatorVesting<'info> {
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+}
+
+/// Return the creator's vesting status as instruction return data (permissionless, read-only)
+/// Meant to be simulated so clients do not have to reimplement the vesting math
+pub fn get_creator_vesting(ctx: Context<GetCreatorVesting>) -> Result<CreatorVestingView> {
+    let clock = Clock::get()?;
+
+    let (lock_end_time, unlock_end_time, claimable_amount, is_locked) =
+        ctx.accounts.launch_pool.get_creator_unlock_info(clock.unix_timestamp);
+
+    msg!("Creator vesting: {} claimable, locked: {}", claimable_amount, is_locked);
+
+    Ok(CreatorVestingView {
+        lock_end_time,
+        unlock_end_time,
+        claimable_amount,
+        is_locked,
+    })
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LAUNCH_POOL_SEED, PRICE_SCALE};
+use crate::errors::LaunchpadError;
+use crate::events::ImpliedPrice;
+use crate::state::{LaunchPool, LaunchStatus};
+
+#[derive(Accounts)]
+pub struct GetImpliedPrice<'info> {
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+}
+
+/// Emit the launch's implied opening price in lamports per whole token (permissionless, read-only)
+/// Before finalization the price is projected from target_sol, since liquidity_sol is capped at the target.
+/// After finalization it uses liquidity_sol, which migration sets to the SOL actually deposited into the
+/// Meteora pool, so for migrated pools this is the opening pool price rather than the live market price.
+pub fn get_implied_price(ctx: Context<GetImpliedPrice>) -> Result<()> {
+    let launch_pool = &ctx.accounts.launch_pool;
+    let clock = Clock::get()?;
+
+    let (quote_amount, is_projected) = match launch_pool.status {
+        LaunchStatus::Initialized | LaunchStatus::Active => (launch_pool.target_sol, true),
+        LaunchStatus::Success | LaunchStatus::Migrated => (launch_pool.liquidity_sol, false),
+        LaunchStatus::Failed => return Err(LaunchpadError::LaunchFailed.into()),
+    };
+    let token_amount = launch_pool.liquidity_allocation;
+    require!(token_amount > 0, LaunchpadError::InsufficientLiquidity);
+
+    // lamports per whole token = quote * 10^decimals / tokens
+    let price_scaled = (quote_amount as u128)
+        .checked_mul(10u128.pow(launch_pool.token_decimals as u32))
+        .and_then(|value| value.checked_mul(PRICE_SCALE))
+        .ok_or(LaunchpadError::MathOverflow)?
+        .checked_div(token_amount as u128)
+        .ok_or(LaunchpadError::DivisionByZero)?;
+
+    emit!(ImpliedPrice {
+        pool: launch_pool.key(),
+        status: launch_pool.status as u8,
+        price_scaled,
+        price_scale: PRICE_SCALE,
+        quote_amount,
+        token_amount,
+        is_projected,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Implied price: {} lamports per token (scaled by {})", price_scaled, PRICE_SCALE);
+
+    Ok(())
+}
@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::state::PlatformStats;
+
+/// Platform totals returned by get_platform_stats
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PlatformStatsView {
+    /// Number of launches finalized as success or failure
+    pub total_pools: u64,
+    /// Total SOL raised by migrated launches
+    pub total_raised_sol: u64,
+    /// Number of launches finalized as success
+    pub total_successful: u64,
+    /// Number of launches finalized as failure
+    pub total_failed: u64,
+}
+
+#[derive(Accounts)]
+pub struct GetPlatformStats<'info> {
+    #[account(
+        seeds = [PlatformStats::SEED],
+        bump = platform_stats.bump,
+    )]
+    pub platform_stats: Account<'info, PlatformStats>,
+}
+
+/// Return the platform totals as instruction return data (permissionless, read-only)
+/// Meant to be simulated by analytics clients
+pub fn get_platform_stats(ctx: Context<GetPlatformStats>) -> Result<PlatformStatsView> {
+    let stats = &ctx.accounts.platform_stats;
+
+    msg!(
+        "Platform stats: {} pools, {} successful, {} failed",
+        stats.total_pools,
+        stats.total_successful,
+        stats.total_failed
+    );
+
+    Ok(PlatformStatsView {
+        total_pools: stats.total_pools,
+        total_raised_sol: stats.total_raised_sol,
+        total_successful: stats.total_successful,
+        total_failed: stats.total_failed,
+    })
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LAUNCH_POOL_SEED;
+use crate::errors::LaunchpadError;
+use crate::events::RemainingCapacity;
+use crate::state::LaunchPool;
+
+#[derive(Accounts)]
+pub struct GetRemainingCapacity<'info> {
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+}
+
+/// Emit how much SOL is left before the target and the sale tokens it represents (permissionless, read-only)
+/// Once the target is reached this reports zero, and further contributions up to hard_cap_sol
+/// are accepted but refunded pro rata as excess SOL after migration
+pub fn get_remaining_capacity(ctx: Context<GetRemainingCapacity>) -> Result<()> {
+    let launch_pool = &ctx.accounts.launch_pool;
+    let clock = Clock::get()?;
+
+    let remaining_sol = launch_pool.remaining_capacity_sol();
+
+    // Sale tokens are priced at sale_allocation / target_sol once the target is met
+    let remaining_tokens = if launch_pool.target_sol == 0 {
+        0
+    } else {
+        let tokens = (remaining_sol as u128)
+            .checked_mul(launch_pool.sale_allocation as u128)
+            .ok_or(LaunchpadError::MathOverflow)?
+            .checked_div(launch_pool.target_sol as u128)
+            .ok_or(LaunchpadError::DivisionByZero)?;
+        u64::try_from(tokens).map_err(|_| LaunchpadError::TypeCastFailed)?
+    };
+
+    emit!(RemainingCapacity {
+        pool: launch_pool.key(),
+        remaining_sol,
+        remaining_tokens,
+        raised_sol: launch_pool.raised_sol,
+        target_sol: launch_pool.target_sol,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Remaining capacity: {} SOL lamports, {} tokens", remaining_sol, remaining_tokens);
+
+    Ok(())
+}
@@ -1,7 +1,7 @@
 use anchor_lang::prelude::*;
 
 use crate::constants::*;
-use crate::state::GlobalConfig;
+use crate::state::{GlobalConfig, PlatformStats};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitializeConfigParams {
@@ -28,6 +28,16 @@ pub struct InitializeConfig<'info> {
     )]
     pub global_config: Box<Account<'info, GlobalConfig>>,
 
+    /// Platform totals, holding the launch pool index to resume from after a close_config
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = PlatformStats::SIZE,
+        seeds = [PlatformStats::SEED],
+        bump,
+    )]
+    pub platform_stats: Box<Account<'info, PlatformStats>>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -45,6 +55,11 @@ pub fn initialize_config(
         ctx.bumps.global_config,
     );
 
+    // Continue the launch pool index of any earlier config so pool addresses stay unique
+    let platform_stats = &mut ctx.accounts.platform_stats;
+    platform_stats.bump = ctx.bumps.platform_stats;
+    config.pool_count = platform_stats.next_pool_index;
+
     // Then override default values with parameters
     if let Some(points_per_sol) = params.points_per_sol {
         config.points_per_sol = points_per_sol;
@@ -66,6 +81,8 @@ pub fn initialize_config(
         config.max_duration = max_duration;
     }
 
+    config.validate_launch_bounds()?;
+
     msg!("Global config initialized successfully");
     msg!("Admin: {}", config.admin);
     msg!("Points signer: {}", config.points_signer);
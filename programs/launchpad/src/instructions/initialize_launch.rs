@@ -1,17 +1,15 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
-use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use anchor_lang::system_program;
+use anchor_spl::token::Token;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface};
 use anchor_spl::associated_token::AssociatedToken;
-use anchor_spl::metadata::{
-    create_metadata_accounts_v3,
-    CreateMetadataAccountsV3,
-    Metadata,
-};
-use mpl_token_metadata::types::DataV2;
+use anchor_spl::metadata::{CreateMetadataAccountsV3, Metadata};
+use anchor_spl::token_interface::spl_token_2022::instruction::AuthorityType;
 
 use crate::constants::*;
-use crate::state::{GlobalConfig, LaunchPool, LaunchStatus};
-use crate::utils::token::calculate_token_allocations;
+use crate::state::{GlobalConfig, LaunchPool, LaunchStatus, SymbolRegistry};
+use crate::utils::token::{calculate_token_allocations, create_launch_token_metadata, launch_total_supply, revoke_launch_mint_authority};
 use crate::events::LaunchPoolInitialized;
 use crate::errors::LaunchpadError;
 
@@ -21,10 +19,35 @@ pub struct InitializeLaunchParams {
     pub token_symbol: String,
     pub token_uri: String,
     pub target_sol: Option<u64>,  // Use default 100 SOL if not provided
+    pub soft_cap_sol: Option<u64>,  // Minimum raise to succeed, defaults to target_sol
+    pub hard_cap_sol: Option<u64>,  // Maximum raise, defaults to twice target_sol
     pub duration: Option<i64>,    // Use default 12 hours if not provided
     pub lock_duration: Option<i64>,  // Creator token lock duration (in seconds)
     pub linear_unlock_duration: Option<i64>,  // Creator token linear unlock duration (in seconds)
     pub start_time: Option<i64>, // start time
+    pub near_miss_bps: Option<u16>,  // Share of target (bps) that qualifies for an extension
+    pub near_miss_extension: Option<i64>,  // Seconds added per extension
+    pub max_extensions: Option<u8>,  // Maximum automatic extensions, disabled if not provided
+    pub max_participations_per_user: Option<u32>,  // Unlimited if not provided
+    pub min_contribution_per_user: Option<u64>,  // Use default 0.1 SOL if not provided
+    pub max_contribution_per_user: Option<u64>,  // Use default 3 SOL if not provided
+    pub min_points_to_participate: Option<u64>,  // No minimum points balance if not provided
+    pub claim_start_time: Option<i64>,  // Claims open right after migration if not provided
+    pub gate_excess_claims: Option<bool>,  // Whether excess SOL also waits for claim_start_time
+    pub create_metadata: Option<bool>,  // Create Metaplex metadata, defaults to true, otherwise create_launch_metadata adds it later
+    pub escrow_creator_fees: Option<bool>,  // Hold the creator's pool fee share in escrow, defaults to false
+    pub whitelist_root: Option<[u8; 32]>,  // Merkle root of allowed wallets, open participation if not provided
+    pub decimals: Option<u8>,  // Token decimals (0..=9), use default 6 if not provided
+    pub total_supply: Option<u64>,  // Total supply in base units, defaults to 1 billion whole tokens
+    pub creator_pct: Option<u8>,  // Creator allocation percent, use default 30 if not provided
+    pub sale_pct: Option<u8>,  // Sale allocation percent, use default 50 if not provided
+    pub liquidity_pct: Option<u8>,  // Liquidity allocation percent, use default 20 if not provided
+    pub allow_open_participation: Option<bool>,  // Accept SOL contributions without points, defaults to false
+    pub min_participants: Option<u32>,  // Minimum participants for success, no minimum if not provided
+    pub meteora_base_fee_bps: Option<u16>,  // Meteora pool base fee, use default 150 bps if not provided
+    pub permanent_lock_bps: Option<u16>,  // Share of liquidity locked at migration, use default 50% if not provided
+    pub points_per_sol: Option<u64>,  // Points-to-SOL rate for this launch, use global_config.points_per_sol if not provided
+    pub grace_period: Option<i64>,  // Seconds after end_time before a sub-target launch can fail, defaults to 0
 }
 
 #[derive(Accounts)]
@@ -66,11 +89,12 @@ pub struct InitializeLaunch<'info> {
         payer = creator,
         seeds = [TOKEN_MINT_SEED, launch_pool.key().as_ref()],
         bump,
-        mint::decimals = TOKEN_DECIMALS,
+        mint::decimals = params.decimals.unwrap_or(TOKEN_DECIMALS),
         mint::authority = launch_pool.key(),
         mint::freeze_authority = launch_pool.key(),
+        mint::token_program = token_program,
     )]
-    pub token_mint: Account<'info, Mint>,
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// Launch pool token vault
     #[account(
@@ -82,13 +106,13 @@ pub struct InitializeLaunch<'info> {
         token::authority = vault_authority,
         token::token_program = token_program,
     )]
-    pub token_vault: Account<'info, TokenAccount>,
+    pub token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
     /// CHECK: WSOL mint (verified by address)
     #[account(
         address = anchor_spl::token::spl_token::native_mint::ID
     )]
-    pub wsol_mint: Account<'info, Mint>,
+    pub wsol_mint: Box<InterfaceAccount<'info, Mint>>,
 
     /// Launch pool WSOL vault (for storing raised SOL)
     #[account(
@@ -98,31 +122,43 @@ pub struct InitializeLaunch<'info> {
         bump,
         token::mint = wsol_mint,
         token::authority = vault_authority,
+        token::token_program = quote_token_program,
     )]
-    pub wsol_vault: Account<'info, TokenAccount>,
+    pub wsol_vault: Box<InterfaceAccount<'info, TokenAccount>>,
 
-    /// Token metadata account
+    /// CHECK: Symbol registry PDA, only created when symbol uniqueness is enforced
+    #[account(
+        mut,
+        seeds = [SymbolRegistry::SEED, &SymbolRegistry::hash_symbol(&params.token_symbol)],
+        bump,
+    )]
+    pub symbol_registry: UncheckedAccount<'info>,
+
+    /// Token metadata account, omitted when the launch manages metadata externally
     /// CHECK: Validated by Metaplex program
     #[account(
         mut,
         seeds = [
             b"metadata",
-            metadata_program.key().as_ref(),
+            mpl_token_metadata::ID.as_ref(),
             token_mint.key().as_ref(),
         ],
-        seeds::program = metadata_program.key(),
+        seeds::program = mpl_token_metadata::ID,
         bump,
     )]
-    pub metadata: UncheckedAccount<'info>,
+    pub metadata: Option<UncheckedAccount<'info>>,
+
+    /// Token program of the launch token, classic SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
+    /// Token program of the WSOL vault, WSOL is always a classic SPL Token mint
+    pub quote_token_program: Program<'info, Token>,
 
     /// Associated Token program
     pub associated_token_program: Program<'info, AssociatedToken>,
 
-    /// Metadata program
-    pub metadata_program: Program<'info, Metadata>,
+    /// Metadata program, omitted when the launch manages metadata externally
+    pub metadata_program: Option<Program<'info, Metadata>>,
 
     /// System program
     pub system_program: Program<'info, System>,
@@ -151,13 +187,90 @@ pub fn initialize_launch(
     let linear_unlock_duration = params.linear_unlock_duration.unwrap_or(DEFAULT_CREATOR_LINEAR_UNLOCK_DURATION);
     let start_time = params.start_time.unwrap_or(clock.unix_timestamp);
 
-    // Validate start_time must be in the future
-    if start_time < clock.unix_timestamp {
-        return Err(LaunchpadError::InvalidStartTime.into());
-    }
+    // Validate start_time is not in the past nor scheduled too far ahead
+    global_config.validate_start_time(start_time, clock.unix_timestamp)?;
 
     global_config.validate_launch_params(target_sol, duration)?;
 
+    // Validate token decimals and supply
+    let decimals = params.decimals.unwrap_or(TOKEN_DECIMALS);
+    let total_supply = launch_total_supply(decimals, params.total_supply)?;
+
+    // Validate soft cap, which cannot exceed the target
+    let soft_cap_sol = params.soft_cap_sol.unwrap_or(target_sol);
+    require!(
+        soft_cap_sol > 0 && soft_cap_sol <= target_sol,
+        LaunchpadError::InvalidTargetAmount
+    );
+
+    // Validate hard cap, which cannot be below the target
+    let hard_cap_sol = match params.hard_cap_sol {
+        Some(hard_cap_sol) => hard_cap_sol,
+        None => target_sol
+            .checked_mul(DEFAULT_HARD_CAP_MULTIPLIER)
+            .ok_or(LaunchpadError::MathOverflow)?,
+    };
+    require!(hard_cap_sol >= target_sol, LaunchpadError::InvalidTargetAmount);
+
+    // Validate the Meteora pool base fee against the hard maximum and the admin-configured bounds
+    let meteora_base_fee_bps = params.meteora_base_fee_bps.unwrap_or(DEFAULT_POOL_FEE_BPS);
+    require!(meteora_base_fee_bps <= MAX_POOL_FEE_BPS, LaunchpadError::InvalidAmount);
+    global_config.validate_pool_fee_bps(meteora_base_fee_bps)?;
+
+    let permanent_lock_bps = params.permanent_lock_bps.unwrap_or(DEFAULT_PERMANENT_LOCK_BPS);
+    require!(permanent_lock_bps as u64 <= MAX_BASIS_POINT, LaunchpadError::InvalidAmount);
+
+    let points_per_sol = params.points_per_sol.unwrap_or(global_config.points_per_sol);
+    require!(points_per_sol > 0, LaunchpadError::InvalidPointsAmount);
+
+    let grace_period = params.grace_period.unwrap_or(0);
+    require!(
+        (0..=MAX_FINALIZE_GRACE_PERIOD).contains(&grace_period),
+        LaunchpadError::InvalidDuration
+    );
+
+    // Validate claim window, which cannot open before the launch ends
+    let claim_start_time = params.claim_start_time.unwrap_or(0);
+    require!(
+        claim_start_time == 0 || claim_start_time >= start_time + duration,
+        LaunchpadError::InvalidStartTime
+    );
+
+    // Validate per-user contribution limits
+    let min_contribution_per_user = params.min_contribution_per_user.unwrap_or(MIN_CONTRIBUTION_PER_USER);
+    let max_contribution_per_user = params.max_contribution_per_user.unwrap_or(MAX_CONTRIBUTION_PER_USER);
+    require!(
+        min_contribution_per_user > 0 && min_contribution_per_user <= max_contribution_per_user,
+        LaunchpadError::InvalidContribution
+    );
+
+    // Validate near-miss extension parameters
+    let near_miss_bps = params.near_miss_bps.unwrap_or(0);
+    let near_miss_extension = params.near_miss_extension.unwrap_or(0);
+    let max_extensions = params.max_extensions.unwrap_or(0);
+    require!(
+        max_extensions <= MAX_NEAR_MISS_EXTENSIONS
+            && near_miss_bps as u64 <= MAX_BASIS_POINT
+            && (0..=MAX_NEAR_MISS_EXTENSION_DURATION).contains(&near_miss_extension),
+        LaunchpadError::InvalidExtensionParams
+    );
+    require!(
+        max_extensions == 0 || (near_miss_bps > 0 && near_miss_extension > 0),
+        LaunchpadError::InvalidExtensionParams
+    );
+
+    // Claim the token symbol if uniqueness is enforced
+    if global_config.enforce_unique_symbols {
+        register_symbol(
+            &ctx.accounts.symbol_registry,
+            &creator.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            &params.token_symbol,
+            launch_pool.key(),
+            ctx.bumps.symbol_registry,
+        )?;
+    }
+
     // Initialize launch pool
     launch_pool.creator = creator.key();
     launch_pool.token_mint = token_mint.key();
@@ -169,9 +282,17 @@ pub fn initialize_launch(
     launch_pool.bump = ctx.bumps.launch_pool;
 
     // Set token allocation
-    launch_pool.total_supply = TOTAL_SUPPLY;
+    launch_pool.total_supply = total_supply;
+    launch_pool.token_decimals = decimals;
+    let creator_pct = params.creator_pct.unwrap_or(CREATOR_ALLOCATION_PERCENT);
+    let sale_pct = params.sale_pct.unwrap_or(SALE_ALLOCATION_PERCENT);
+    let liquidity_pct = params.liquidity_pct.unwrap_or(LIQUIDITY_ALLOCATION_PERCENT);
     let (creator_allocation, sale_allocation, liquidity_allocation) =
-        calculate_token_allocations(TOTAL_SUPPLY)?;
+        calculate_token_allocations(total_supply, creator_pct, sale_pct, liquidity_pct)?;
+
+    launch_pool.creator_pct = creator_pct;
+    launch_pool.sale_pct = sale_pct;
+    launch_pool.liquidity_pct = liquidity_pct;
 
     launch_pool.creator_allocation = creator_allocation;
     launch_pool.sale_allocation = sale_allocation;
@@ -179,17 +300,46 @@ pub fn initialize_launch(
 
     // Set fundraising parameters
     launch_pool.target_sol = target_sol;
+    launch_pool.soft_cap_sol = soft_cap_sol;
+    launch_pool.hard_cap_sol = hard_cap_sol;
     launch_pool.raised_sol = 0;
     launch_pool.liquidity_sol = 0;
     launch_pool.excess_sol = 0;
+    launch_pool.excess_sol_distributed = 0;
+    launch_pool.excess_converted = false;
+    launch_pool.excess_claim_count = 0;
+    launch_pool.excess_dust_swept = false;
 
     // Set time parameters
     launch_pool.start_time = start_time;
     launch_pool.end_time = start_time + duration;
     launch_pool.finalized_time = 0;
 
+    // Set near-miss extension parameters
+    launch_pool.near_miss_bps = near_miss_bps;
+    launch_pool.near_miss_extension = near_miss_extension;
+    launch_pool.max_extensions = max_extensions;
+    launch_pool.extensions_used = 0;
+
+    // Set participation limits
+    launch_pool.max_participations_per_user = params.max_participations_per_user.unwrap_or(0);
+    launch_pool.min_points_to_participate = params.min_points_to_participate.unwrap_or(0);
+    launch_pool.min_contribution_per_user = min_contribution_per_user;
+    launch_pool.max_contribution_per_user = max_contribution_per_user;
+    launch_pool.paused = false;
+    launch_pool.whitelist_root = params.whitelist_root;
+    launch_pool.allow_open_participation = params.allow_open_participation.unwrap_or(false);
+    launch_pool.min_participants = params.min_participants.unwrap_or(0);
+    launch_pool.meteora_base_fee_bps = meteora_base_fee_bps;
+    launch_pool.permanent_lock_bps = permanent_lock_bps;
+    launch_pool.grace_period = grace_period;
+
+    // Set claim window
+    launch_pool.claim_start_time = claim_start_time;
+    launch_pool.gate_excess_claims = params.gate_excess_claims.unwrap_or(false);
+
     // Set points configuration
-    launch_pool.points_per_sol = global_config.points_per_sol;
+    launch_pool.points_per_sol = points_per_sol;
     launch_pool.total_points_consumed = 0;
 
     // Set creator lock configuration
@@ -206,6 +356,26 @@ pub fn initialize_launch(
     // Initialize Meteora fields (will be set after migration)
     launch_pool.position = None;
     launch_pool.position_nft_account = None;
+    launch_pool.creator_fee_recipient_override = None;
+    launch_pool.escrow_creator_fees = params.escrow_creator_fees.unwrap_or(false);
+
+    // Take the creator bond, held as lamports on the launch pool account
+    let creator_bond = global_config.creator_bond;
+    launch_pool.creator_bond = creator_bond;
+    launch_pool.bond_settled = false;
+    if creator_bond > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: creator.to_account_info(),
+                    to: launch_pool.to_account_info(),
+                },
+            ),
+            creator_bond,
+        )?;
+        msg!("Creator bond deposited: {} lamports", creator_bond);
+    }
 
     // Mint all tokens to vault
     let creator_key = ctx.accounts.creator.key();
@@ -217,81 +387,74 @@ pub fn initialize_launch(
     ];
     let signer_seeds = &[&seeds[..]];
 
-    token::mint_to(
+    token_interface::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
-            token::MintTo {
+            token_interface::MintTo {
                 mint: ctx.accounts.token_mint.to_account_info(),
                 to: ctx.accounts.token_vault.to_account_info(),
                 authority: launch_pool.to_account_info(),
             },
             signer_seeds,
         ),
-        TOTAL_SUPPLY,
+        total_supply,
     )?;
 
-    // Create metadata
-    let metadata_accounts = CreateMetadataAccountsV3 {
-        metadata: ctx.accounts.metadata.to_account_info(),
-        mint: ctx.accounts.token_mint.to_account_info(),
-        mint_authority: launch_pool.to_account_info(),
-        payer: ctx.accounts.creator.to_account_info(),
-        update_authority: launch_pool.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        rent: ctx.accounts.rent.to_account_info(),
-    };
-
-    let data = DataV2 {
-        name: params.token_name.clone(),
-        symbol: params.token_symbol.clone(),
-        uri: params.token_uri,
-        seller_fee_basis_points: 0,
-        creators: None,
-        collection: None,
-        uses: None,
-    };
-
-    create_metadata_accounts_v3(
-        CpiContext::new_with_signer(
-            ctx.accounts.metadata_program.to_account_info(),
+    // Create metadata now, or leave the mint authority with the pool so create_launch_metadata can do it later
+    let create_metadata = params.create_metadata.unwrap_or(true);
+    if create_metadata {
+        let (Some(metadata), Some(metadata_program)) =
+            (&ctx.accounts.metadata, &ctx.accounts.metadata_program)
+        else {
+            return Err(LaunchpadError::MetadataAccountsRequired.into());
+        };
+
+        let metadata_accounts = CreateMetadataAccountsV3 {
+            metadata: metadata.to_account_info(),
+            mint: ctx.accounts.token_mint.to_account_info(),
+            mint_authority: launch_pool.to_account_info(),
+            payer: ctx.accounts.creator.to_account_info(),
+            update_authority: launch_pool.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            rent: ctx.accounts.rent.to_account_info(),
+        };
+
+        create_launch_token_metadata(
+            metadata_program.to_account_info(),
             metadata_accounts,
+            params.token_name.clone(),
+            params.token_symbol.clone(),
+            params.token_uri,
             signer_seeds,
-        ),
-        data,
-        false,  // is_mutable
-        true,  // update_authority_is_signer
-        None,  // collection_details
-    )?;
+        )?;
 
-    // Revoke authority (set to None)
-    token::set_authority(
-        CpiContext::new_with_signer(
+        revoke_launch_mint_authority(
             ctx.accounts.token_program.to_account_info(),
-            token::SetAuthority {
-                current_authority: launch_pool.to_account_info(),
-                account_or_mint: ctx.accounts.token_mint.to_account_info(),
-            },
+            ctx.accounts.token_mint.to_account_info(),
+            launch_pool.to_account_info(),
+            AuthorityType::MintTokens,
             signer_seeds,
-        ),
-        token::spl_token::instruction::AuthorityType::MintTokens,
-        None,
-    )?;
-    token::set_authority(
-        CpiContext::new_with_signer(
-            ctx.accounts.token_program.to_account_info(),
-            token::SetAuthority {
-                current_authority: launch_pool.to_account_info(),
-                account_or_mint: ctx.accounts.token_mint.to_account_info(),
-            },
-            signer_seeds,
-        ),
-        token::spl_token::instruction::AuthorityType::FreezeAccount,
-        None,
+        )?;
+    } else {
+        msg!("Skipping metadata creation, mint authority stays with the pool until create_launch_metadata");
+    }
+
+    // Metadata never needs the freeze authority, so it is always revoked
+    revoke_launch_mint_authority(
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.token_mint.to_account_info(),
+        launch_pool.to_account_info(),
+        AuthorityType::FreezeAccount,
+        signer_seeds,
     )?;
 
-    // Set status to Active
-    launch_pool.status = LaunchStatus::Active;
-    global_config.pool_count += 1;
+    // Launches starting now are active right away, scheduled ones wait for activate_launch
+    launch_pool.status = if start_time <= clock.unix_timestamp {
+        LaunchStatus::Active
+    } else {
+        LaunchStatus::Initialized
+    };
+    global_config.record_pool_created()?;
 
     // Emit launch pool initialized event
     emit!(LaunchPoolInitialized {
@@ -300,7 +463,7 @@ pub fn initialize_launch(
         token_mint: token_mint.key(),
         token_name: params.token_name,
         token_symbol: params.token_symbol,
-        total_supply: TOTAL_SUPPLY,
+        total_supply,
         target_sol,
         duration,
         points_per_sol: launch_pool.points_per_sol,
@@ -318,3 +481,66 @@ pub fn initialize_launch(
 
     Ok(())
 }
+
+/// Create the symbol registry PDA, failing if another launch already holds the symbol
+fn register_symbol<'info>(
+    symbol_registry: &UncheckedAccount<'info>,
+    creator: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    token_symbol: &str,
+    launch_pool: Pubkey,
+    bump: u8,
+) -> Result<()> {
+    require!(symbol_registry.data_is_empty(), LaunchpadError::DuplicateSymbol);
+
+    let symbol_hash = SymbolRegistry::hash_symbol(token_symbol);
+    let seeds: &[&[u8]] = &[SymbolRegistry::SEED, &symbol_hash, &[bump]];
+    let signer_seeds = &[seeds];
+    let registry_info = symbol_registry.to_account_info();
+
+    // Top up rent and allocate, tolerating lamports sent to the address ahead of time
+    let required_lamports = Rent::get()?
+        .minimum_balance(SymbolRegistry::SIZE)
+        .saturating_sub(registry_info.lamports());
+    if required_lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: creator.clone(),
+                    to: registry_info.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Allocate { account_to_allocate: registry_info.clone() },
+            signer_seeds,
+        ),
+        SymbolRegistry::SIZE as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.clone(),
+            system_program::Assign { account_to_assign: registry_info.clone() },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )?;
+
+    let registry = SymbolRegistry {
+        symbol_hash,
+        launch_pool,
+        creator: creator.key(),
+        bump,
+        reserved: [0; 4],
+    };
+    registry.try_serialize(&mut &mut registry_info.try_borrow_mut_data()?[..])?;
+
+    msg!("Registered token symbol {}", token_symbol.trim());
+
+    Ok(())
+}
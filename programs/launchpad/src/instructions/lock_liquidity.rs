@@ -5,7 +5,7 @@ use crate::constants::VAULT_AUTHORITY;
 use crate::errors::LaunchpadError;
 use crate::events::LiquidityLocked;
 use crate::state::{GlobalConfig, LaunchPool};
-use crate::{cp_amm, const_pda::const_authority::VAULT_BUMP};
+use crate::{cp_amm, const_pda::const_authority::vault_signer_seeds};
 
 /// Lock liquidity in Meteora pool by calling cp_amm's permanent_lock_position
 /// This can be called multiple times to progressively lock liquidity
@@ -69,7 +69,7 @@ pub struct LockLiquidity<'info> {
 
 impl<'info> LockLiquidity<'info> {
     /// Lock the specified amount of liquidity in the Meteora pool
-    pub fn lock_liquidity(&mut self, liquidity_amount: u128) -> Result<()> {
+    pub fn lock_liquidity(&mut self, liquidity_amount: u128, vault_bump: u8) -> Result<()> {
         // Validate liquidity amount is greater than 0
         require!(
             liquidity_amount > 0,
@@ -77,7 +77,7 @@ impl<'info> LockLiquidity<'info> {
         );
 
         // Prepare PDA signer seeds
-        let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
+        let signer_seeds = vault_signer_seeds(vault_bump);
 
         // Call cp_amm's permanent_lock_position via CPI
         cp_amm::cpi::permanent_lock_position(
@@ -115,5 +115,5 @@ impl<'info> LockLiquidity<'info> {
 
 /// Handler function for lock_liquidity instruction
 pub fn handle_lock_liquidity(ctx: Context<LockLiquidity>, liquidity_amount: u128) -> Result<()> {
-    ctx.accounts.lock_liquidity(liquidity_amount)
+    ctx.accounts.lock_liquidity(liquidity_amount, ctx.bumps.vault_authority)
 }
@@ -8,12 +8,13 @@ use cp_amm::types::{
 };
 use std::u64;
 
-use crate::{const_pda::const_authority::VAULT_BUMP, constants::{FEE_DENOMINATOR, MAX_BASIS_POINT, MAX_SQRT_PRICE, MIN_SQRT_PRICE, SQRT_PRICE, TOKEN_VAULT}, cp_amm, state::GlobalConfig};
+use crate::{const_pda::const_authority::vault_signer_seeds, constants::{FEE_DENOMINATOR, MAX_BASIS_POINT, MAX_SQRT_PRICE, MIN_SQRT_PRICE, TOKEN_VAULT}, cp_amm, state::GlobalConfig};
 use crate::constants::{LAUNCH_POOL_SEED, VAULT_AUTHORITY};
 use crate::errors::LaunchpadError;
 use crate::events::LiquidityPoolCreated;
-use crate::state::{LaunchPool, LaunchStatus};
-use crate::utils::{get_liquidity_for_adding_liquidity};
+use crate::state::{LaunchPool, LaunchStatus, PlatformStats};
+use crate::utils::{compute_sqrt_price_from_amounts, get_liquidity_for_adding_liquidity, settle_creator_bond};
+use crate::instructions::check_pool_config;
 
 #[derive(Accounts)]
 pub struct DammV2<'info> {
@@ -28,7 +29,6 @@ pub struct DammV2<'info> {
         mut,
         seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
         bump = launch_pool.bump,
-        constraint = launch_pool.is_success() @ LaunchpadError::LaunchFailed,
     )]
     pub launch_pool: Box<Account<'info, LaunchPool>>,
 
@@ -101,6 +101,21 @@ pub struct DammV2<'info> {
         constraint = payer.key() == launch_pool.creator || payer.key() == global_config.admin @ LaunchpadError::Unauthorized
     )]
     pub payer: Signer<'info>,
+    /// Launch creator, receives the creator bond refund
+    #[account(
+        mut,
+        address = launch_pool.creator @ LaunchpadError::Unauthorized,
+    )]
+    pub creator: SystemAccount<'info>,
+    /// Platform-wide launch totals
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = PlatformStats::SIZE,
+        seeds = [PlatformStats::SEED],
+        bump,
+    )]
+    pub platform_stats: Box<Account<'info, PlatformStats>>,
     /// CHECK: token_program
     pub token_base_program: Interface<'info, TokenInterface>,
     /// CHECK: token_program
@@ -115,12 +130,12 @@ pub struct DammV2<'info> {
 }
 
 impl<'info> DammV2<'info> {
-    fn initialize_pool(&mut self) -> Result<()> {
+    fn initialize_pool(&mut self, vault_bump: u8) -> Result<()> {
         let base_amount: u64 = self.launch_pool.liquidity_allocation;
         let quote_amount: u64 = self.launch_pool.liquidity_sol;
 
         // Calculate fair sqrt_price based on actual token amounts
-        let sqrt_price = SQRT_PRICE;
+        let sqrt_price = compute_sqrt_price_from_amounts(base_amount, quote_amount)?;
 
         // Validate calculated sqrt_price is within reasonable bounds
         require!(
@@ -136,10 +151,10 @@ impl<'info> DammV2<'info> {
             MAX_SQRT_PRICE,
         )?;
 
-        // Calculate 1.5% fee numerator
-        // 1.5% = 150 BPS
-        // numerator = 150 * FEE_DENOMINATOR / MAX_BASIS_POINT
-        let base_fee_numerator = (150u128 * FEE_DENOMINATOR as u128 / MAX_BASIS_POINT as u128) as u64;
+        // Calculate the launch's base fee numerator
+        // numerator = base_fee_bps * FEE_DENOMINATOR / MAX_BASIS_POINT
+        let base_fee_bps = self.launch_pool.meteora_base_fee_bps;
+        let base_fee_numerator = (base_fee_bps as u128 * FEE_DENOMINATOR as u128 / MAX_BASIS_POINT as u128) as u64;
 
         // Create fee parameters
         let base_fee = BaseFeeParameters {
@@ -168,7 +183,7 @@ impl<'info> DammV2<'info> {
             activation_point: None,
         };
 
-        let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
+        let signer_seeds = vault_signer_seeds(vault_bump);
         cp_amm::cpi::initialize_pool_with_dynamic_config(
             CpiContext::new_with_signer(
                 self.amm_program.to_account_info(),
@@ -200,30 +215,37 @@ impl<'info> DammV2<'info> {
             initialize_pool_params,
         )?;
 
-        cp_amm::cpi::permanent_lock_position(
-            CpiContext::new_with_signer(
-                self.amm_program.to_account_info(),
-                cp_amm::cpi::accounts::PermanentLockPosition {
-                    pool: self.pool.to_account_info(),
-                    position: self.position.to_account_info(),
-                    position_nft_account: self.position_nft_account.to_account_info(),
-                    owner: self.vault_authority.to_account_info(),
-                    event_authority: self.damm_event_authority.to_account_info(),
-                    program: self.amm_program.to_account_info(),
-                },
-                signer_seeds,
-            ),
-            liquidity/2,
-        )?;
+        // Permanently lock the launch's share of liquidity, the rest stays lockable via lock_liquidity
+        let locked_liquidity = liquidity
+            .checked_mul(self.launch_pool.permanent_lock_bps as u128)
+            .ok_or(LaunchpadError::MathOverflow)?
+            / MAX_BASIS_POINT as u128;
+        if locked_liquidity > 0 {
+            cp_amm::cpi::permanent_lock_position(
+                CpiContext::new_with_signer(
+                    self.amm_program.to_account_info(),
+                    cp_amm::cpi::accounts::PermanentLockPosition {
+                        pool: self.pool.to_account_info(),
+                        position: self.position.to_account_info(),
+                        position_nft_account: self.position_nft_account.to_account_info(),
+                        owner: self.vault_authority.to_account_info(),
+                        event_authority: self.damm_event_authority.to_account_info(),
+                        program: self.amm_program.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                locked_liquidity,
+            )?;
+        }
 
         Ok(())
     }
 
-    pub fn create_pool(&mut self) -> Result<()> {
-        // Verify launch pool is in correct state
+    pub fn create_pool(&mut self, vault_bump: u8, stats_bump: u8) -> Result<()> {
+        // Verify launch pool is in correct state (checked here so complete_launch can finalize first)
         require!(
             self.launch_pool.status == LaunchStatus::Success,
-            LaunchpadError::InvalidLaunchStatus
+            LaunchpadError::LaunchFailed
         );
 
         // Verify we have sufficient liquidity to create pool
@@ -232,6 +254,18 @@ impl<'info> DammV2<'info> {
             LaunchpadError::InsufficientLiquidity
         );
 
+        // Fail early with a clear error if the config cannot be used for this migration
+        check_pool_config(&self.pool_config, &self.vault_authority.key())?;
+
+        // Guard against launches opening with too little liquidity relative to the raise
+        self.global_config.validate_liquidity_ratio(
+            self.launch_pool.liquidity_sol,
+            self.launch_pool.raised_sol,
+        )?;
+
+        // Reject trading fees outside the admin-configured bounds
+        self.global_config.validate_pool_fee_bps(self.launch_pool.meteora_base_fee_bps)?;
+
         // Record vault balances before initialize_pool
         let token_vault_before = self.token_vault.amount;
         let wsol_vault_before = self.wsol_vault.amount;
@@ -245,7 +279,7 @@ impl<'info> DammV2<'info> {
         msg!("Token vault: {}", token_vault_before);
         msg!("WSOL vault: {}", wsol_vault_before);
 
-        self.initialize_pool()?;
+        self.initialize_pool(vault_bump)?;
 
         // Reload accounts to get updated balances
         self.token_vault.reload()?;
@@ -295,6 +329,12 @@ impl<'info> DammV2<'info> {
         self.launch_pool.position_nft_account = Some(self.position_nft_account.key());
 
         self.launch_pool.status = LaunchStatus::Migrated;
+        self.platform_stats.bump = stats_bump;
+        self.platform_stats.record_migration(self.launch_pool.raised_sol)?;
+
+        // Refund the creator bond now that the launch has migrated
+        let creator_info = self.creator.to_account_info();
+        settle_creator_bond(&mut self.launch_pool, &creator_info, false)?;
 
         msg!("Creator token unlock will start at: {}", clock.unix_timestamp);
         msg!("Lock duration: {} days", self.launch_pool.creator_lock_duration / (24 * 3600));
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::constants::GLOBAL_CONFIG_SEED;
+use crate::errors::LaunchpadError;
+use crate::events::AccountMigrated;
+use crate::state::GlobalConfig;
+use crate::utils::grow_program_account;
+
+#[derive(Accounts)]
+pub struct MigrateConfig<'info> {
+    /// Admin recorded in the config, pays the extra rent
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    /// CHECK: global config under any past layout, checked by discriminator and admin in the handler
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump,
+    )]
+    pub global_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a global config created under an older layout to GlobalConfig::SIZE (admin only)
+/// Legacy configs also get the defaults of every field added since, so must be migrated
+/// after each upgrade that grows GlobalConfig and before any other instruction loads it
+pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+    let config_info = ctx.accounts.global_config.to_account_info();
+
+    {
+        let data = config_info.try_borrow_data()?;
+        require!(
+            data.len() >= 40 && data[..8] == *GlobalConfig::DISCRIMINATOR,
+            LaunchpadError::InvalidStatus
+        );
+        // The admin is the first field in every layout
+        require!(
+            data[8..40] == ctx.accounts.admin.key().to_bytes(),
+            LaunchpadError::Unauthorized
+        );
+    }
+
+    let old_size = grow_program_account(
+        &config_info,
+        &ctx.accounts.admin.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        GlobalConfig::SIZE,
+    )?;
+    require!(old_size < GlobalConfig::SIZE, LaunchpadError::InvalidStatus);
+
+    let mut global_config = GlobalConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+    if old_size <= GlobalConfig::LEGACY_SIZE {
+        global_config.apply_legacy_defaults();
+    }
+    global_config.validate_launch_bounds()?;
+    global_config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: config_info.key(),
+        old_size: old_size as u64,
+        new_size: GlobalConfig::SIZE as u64,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Global config migrated from {} to {} bytes", old_size, GlobalConfig::SIZE);
+
+    Ok(())
+}
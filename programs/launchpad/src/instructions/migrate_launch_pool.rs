@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+
+use crate::errors::LaunchpadError;
+use crate::events::AccountMigrated;
+use crate::state::LaunchPool;
+use crate::utils::grow_program_account;
+
+#[derive(Accounts)]
+pub struct MigrateLaunchPool<'info> {
+    /// Anyone can migrate a pool, the payer covers the extra rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: launch pool under any past layout, checked by discriminator and PDA in the handler
+    #[account(mut)]
+    pub launch_pool: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Grow a launch pool created under an older layout to LaunchPool::SIZE (permissionless)
+/// Legacy pools also get defaults matching the rules they launched under, so must be migrated
+/// after each upgrade that grows LaunchPool and before any other instruction loads them
+pub fn migrate_launch_pool(ctx: Context<MigrateLaunchPool>) -> Result<()> {
+    let pool_info = ctx.accounts.launch_pool.to_account_info();
+
+    require!(
+        pool_info.try_borrow_data()?.get(..8) == Some(LaunchPool::DISCRIMINATOR),
+        LaunchpadError::InvalidLaunchPool
+    );
+
+    let old_size = grow_program_account(
+        &pool_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        LaunchPool::SIZE,
+    )?;
+    require!(old_size < LaunchPool::SIZE, LaunchpadError::InvalidStatus);
+
+    let mut launch_pool = LaunchPool::try_deserialize(&mut &pool_info.try_borrow_data()?[..])?;
+
+    launch_pool.verify_canonical_address(pool_info.key)?;
+
+    if old_size <= LaunchPool::LEGACY_SIZE {
+        launch_pool.apply_legacy_defaults();
+    }
+    launch_pool.try_serialize(&mut &mut pool_info.try_borrow_mut_data()?[..])?;
+
+    emit!(AccountMigrated {
+        account: pool_info.key(),
+        old_size: old_size as u64,
+        new_size: LaunchPool::SIZE as u64,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Launch pool {} migrated from {} to {} bytes", pool_info.key(), old_size, LaunchPool::SIZE);
+
+    Ok(())
+}
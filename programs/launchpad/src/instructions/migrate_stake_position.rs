@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::LaunchpadError;
+use crate::events::StakePositionMigrated;
+use crate::state::{StakingCounter, StakingPosition};
+
+#[derive(Accounts)]
+pub struct MigrateStakePosition<'info> {
+    /// Owner of the legacy staking position
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Token mint of the staked token
+    pub token_mint: Account<'info, Mint>,
+
+    /// Position created before positions were indexed, closed and replaced by an indexed one
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            StakingPosition::SEED,
+            user.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump = legacy_position.bump,
+        constraint = legacy_position.user == user.key() @ LaunchpadError::NoStakeFound,
+        constraint = legacy_position.token_mint == token_mint.key() @ LaunchpadError::InvalidStakingTokenMint,
+    )]
+    pub legacy_position: Account<'info, StakingPosition>,
+
+    /// Counter assigning position indexes for this user and token
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = StakingCounter::SIZE,
+        seeds = [
+            StakingCounter::SEED,
+            user.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump,
+    )]
+    pub staking_counter: Account<'info, StakingCounter>,
+
+    /// Indexed position taking over the legacy stake, index 0 unless other positions were opened first
+    #[account(
+        init,
+        payer = user,
+        space = StakingPosition::SIZE,
+        seeds = [
+            StakingPosition::SEED,
+            user.key().as_ref(),
+            token_mint.key().as_ref(),
+            &staking_counter.next_position_index.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub staking_position: Account<'info, StakingPosition>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Move a position staked before positions were indexed to the next free index
+/// Legacy positions cannot be unstaked, extended or transferred until they are migrated
+pub fn migrate_stake_position(ctx: Context<MigrateStakePosition>) -> Result<()> {
+    let legacy_position = &ctx.accounts.legacy_position;
+    let position_index = ctx.accounts.staking_counter.next_index(
+        ctx.accounts.user.key(),
+        legacy_position.token_mint,
+        ctx.bumps.staking_counter,
+    )?;
+    ctx.accounts.staking_position.move_from(
+        legacy_position,
+        ctx.accounts.user.key(),
+        position_index,
+        ctx.bumps.staking_position,
+    );
+
+    emit!(StakePositionMigrated {
+        user: ctx.accounts.user.key(),
+        legacy_position: legacy_position.key(),
+        position: ctx.accounts.staking_position.key(),
+        token_mint: legacy_position.token_mint,
+        position_index,
+        staked_amount: legacy_position.staked_amount,
+        unlock_time: legacy_position.unlock_time,
+    });
+
+    msg!(
+        "Legacy staking position of {} migrated to index {}",
+        ctx.accounts.user.key(),
+        position_index
+    );
+
+    // Legacy position account is automatically closed by the 'close = user' constraint
+
+    Ok(())
+}
@@ -1,29 +1,119 @@
+pub mod accept_admin;
+pub mod activate_launch;
+pub mod apply_config;
+pub mod cancel_launch;
+pub mod check_whitelist;
 pub mod claim_creator_tokens;
+pub mod claim_creator_tokens_batch;
+pub mod claim_sol_dividends;
+pub mod claim_staking_rewards;
 pub mod claim_token_dividends;
 pub mod claim_user_rewards;
+pub mod close_config;
+pub mod close_user_position;
 pub mod collect_pool_fees;
+pub mod complete_launch;
+pub mod configure_staking_rewards;
+pub mod convert_excess_to_dividends;
+pub mod create_launch_metadata;
+pub mod deposit_dividends;
+pub mod emergency_withdraw;
+pub mod emit_pool_snapshot;
+pub mod extend_lock;
 pub mod finalize_launch;
+pub mod finalize_launch_batch;
+pub mod finalize_summary;
+pub mod fund_staking_rewards;
+pub mod get_creator_vesting;
+pub mod get_implied_price;
+pub mod get_platform_stats;
+pub mod get_remaining_capacity;
 pub mod initialize_config;
 pub mod initialize_launch;
 pub mod lock_liquidity;
 pub mod meteora_pool;
+pub mod migrate_config;
+pub mod migrate_launch_pool;
+pub mod migrate_stake_position;
+pub mod open_reward_epoch;
+pub mod participate_batch;
 pub mod participate_with_points;
+pub mod participate_with_sol;
+pub mod preview_user_rewards;
+pub mod propose_admin;
+pub mod recompute_raise_split;
+pub mod reconcile_user_points;
+pub mod reinvest_excess;
+pub mod release_symbol;
+pub mod set_creator_fee_recipient;
+pub mod set_pool_paused;
+pub mod settle_launch_pool;
 pub mod stake_tokens;
 pub mod swap;
+pub mod sweep_excess_dust;
+pub mod sweep_unsold_tokens;
+pub mod transfer_stake_position;
 pub mod unstake_tokens;
 pub mod update_config;
+pub mod validate_pool_config;
+pub mod withdraw_creator_fees;
 
+pub use accept_admin::*;
+pub use activate_launch::*;
+pub use apply_config::*;
+pub use cancel_launch::*;
+pub use check_whitelist::*;
 pub use claim_creator_tokens::*;
+pub use claim_creator_tokens_batch::*;
+pub use claim_sol_dividends::*;
+pub use claim_staking_rewards::*;
 pub use claim_token_dividends::*;
 pub use claim_user_rewards::*;
+pub use close_config::*;
+pub use close_user_position::*;
 pub use collect_pool_fees::*;
+pub use complete_launch::*;
+pub use configure_staking_rewards::*;
+pub use convert_excess_to_dividends::*;
+pub use create_launch_metadata::*;
+pub use deposit_dividends::*;
+pub use emergency_withdraw::*;
+pub use emit_pool_snapshot::*;
+pub use extend_lock::*;
 pub use finalize_launch::*;
+pub use finalize_launch_batch::*;
+pub use finalize_summary::*;
+pub use fund_staking_rewards::*;
+pub use get_creator_vesting::*;
+pub use get_implied_price::*;
+pub use get_platform_stats::*;
+pub use get_remaining_capacity::*;
 pub use initialize_config::*;
 pub use initialize_launch::*;
 pub use lock_liquidity::*;
 pub use meteora_pool::*;
+pub use migrate_config::*;
+pub use migrate_launch_pool::*;
+pub use migrate_stake_position::*;
+pub use open_reward_epoch::*;
+pub use participate_batch::*;
 pub use participate_with_points::*;
+pub use participate_with_sol::*;
+pub use preview_user_rewards::*;
+pub use propose_admin::*;
+pub use recompute_raise_split::*;
+pub use reconcile_user_points::*;
+pub use reinvest_excess::*;
+pub use release_symbol::*;
+pub use set_creator_fee_recipient::*;
+pub use set_pool_paused::*;
+pub use settle_launch_pool::*;
 pub use stake_tokens::*;
 pub use swap::*;
+pub use sweep_excess_dust::*;
+pub use sweep_unsold_tokens::*;
+pub use transfer_stake_position::*;
 pub use unstake_tokens::*;
 pub use update_config::*;
+pub use validate_pool_config::*;
+pub use withdraw_creator_fees::*;
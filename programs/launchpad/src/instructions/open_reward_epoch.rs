@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+use crate::constants::{TOKEN_VAULT, VAULT_AUTHORITY};
+use crate::errors::LaunchpadError;
+use crate::events::RewardEpochOpened;
+use crate::state::{GlobalConfig, RewardEpoch};
+
+#[derive(Accounts)]
+pub struct OpenRewardEpoch<'info> {
+    #[account(
+        mut,
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GlobalConfig::SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Account<'info, GlobalConfig>,
+
+    /// vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Token mint of the staked token
+    pub token_mint: Account<'info, Mint>,
+
+    /// Program's token vault holding all staked tokens of this mint
+    #[account(
+        token::mint = token_mint,
+        token::authority = vault_authority,
+        seeds = [TOKEN_VAULT, vault_authority.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+    )]
+    pub token_vault: Account<'info, TokenAccount>,
+
+    /// Reward epoch snapshot for this token
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = RewardEpoch::SIZE,
+        seeds = [RewardEpoch::SEED, token_mint.key().as_ref()],
+        bump,
+    )]
+    pub reward_epoch: Account<'info, RewardEpoch>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a new reward epoch, snapshotting the total staked for the token (admin only)
+pub fn open_reward_epoch(ctx: Context<OpenRewardEpoch>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp;
+    let total_staked = ctx.accounts.token_vault.amount;
+    let token_mint = ctx.accounts.token_mint.key();
+
+    let reward_epoch = &mut ctx.accounts.reward_epoch;
+    reward_epoch.open_next(token_mint, total_staked, current_time, ctx.bumps.reward_epoch)?;
+
+    emit!(RewardEpochOpened {
+        reward_epoch: reward_epoch.key(),
+        token_mint,
+        epoch_index: reward_epoch.epoch_index,
+        total_staked,
+        opened_at: current_time,
+    });
+
+    msg!(
+        "Opened reward epoch {} for mint {} with {} tokens staked",
+        reward_epoch.epoch_index,
+        token_mint,
+        total_staked
+    );
+
+    Ok(())
+}
@@ -0,0 +1,257 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{self, Token, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::instructions::{record_participation, verify_points_signature};
+use crate::state::{GlobalConfig, LaunchPool, UserPoint, UserPosition};
+use crate::utils::{calculate_sol_allowance, check_time_window, clamp_to_contribution_headroom, points_batch_payload, validate_contribution_amount, validate_points_amount, POINTS_BATCH_DOMAIN};
+use crate::events::ContributionClamped;
+
+/// Each pool in the batch is passed as three writable remaining accounts, in the
+/// same order as `points_to_use`: launch pool, user position PDA, pool WSOL vault
+#[derive(Accounts)]
+pub struct ParticipateBatch<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// User points account
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPoint::SIZE,
+        seeds = [USER_POINT_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub user_point: Box<Account<'info, UserPoint>>,
+
+    /// System variables account for Ed25519 signature verification
+    /// CHECK: This is a system-provided instruction system variable
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Contribute to several active pools under one points signature covering the aggregate
+/// Any failing pool aborts the whole transaction, so no partial contributions are written
+pub fn participate_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ParticipateBatch<'info>>,
+    points_to_use: Vec<u64>,
+    total_points: u64,
+    nonce: u64,
+    signature: [u8; 64],
+) -> Result<()> {
+    let batch_size = points_to_use.len();
+    require!(
+        batch_size > 0 && batch_size <= MAX_PARTICIPATE_BATCH_SIZE,
+        LaunchpadError::InvalidBatchSize
+    );
+    require!(
+        ctx.remaining_accounts.len() == batch_size * 3,
+        LaunchpadError::InvalidBatchSize
+    );
+
+    let user = &ctx.accounts.user;
+    let user_point = &mut ctx.accounts.user_point;
+    let clock = Clock::get()?;
+    user_point.user = user.key();
+
+    // The combined points must fit within the signed balance
+    let aggregate_points = points_to_use
+        .iter()
+        .try_fold(0u64, |sum, points| sum.checked_add(*points))
+        .ok_or(LaunchpadError::MathOverflow)?;
+    validate_points_amount(aggregate_points, total_points, user_point.points_consumed)?;
+
+    // Bind the signature to a single use
+    require!(nonce == user_point.participation_nonce, LaunchpadError::InvalidSignature);
+    let payload = points_batch_payload(&user.key(), aggregate_points, total_points, nonce);
+
+    verify_points_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.global_config.points_signer,
+        POINTS_BATCH_DOMAIN,
+        &payload,
+        &signature,
+    )?;
+
+    let mut seen_pools: Vec<Pubkey> = Vec::with_capacity(batch_size);
+
+    for (accounts, pool_points) in ctx.remaining_accounts.chunks(3).zip(points_to_use.iter()) {
+        let (pool_info, position_info, vault_info) = (&accounts[0], &accounts[1], &accounts[2]);
+        require!(
+            pool_info.is_writable && position_info.is_writable && vault_info.is_writable,
+            LaunchpadError::InvalidLaunchPool
+        );
+
+        let mut launch_pool = LaunchPool::load_canonical(pool_info)?;
+
+        // A pool listed twice would have its first write overwritten by the second
+        require!(!seen_pools.contains(&pool_info.key()), LaunchpadError::InvalidLaunchPool);
+        seen_pools.push(pool_info.key());
+
+        require!(launch_pool.is_active(), LaunchpadError::LaunchNotActive);
+        require!(!launch_pool.paused, LaunchpadError::PoolPaused);
+        check_time_window(&launch_pool, clock.unix_timestamp)?;
+        // Whitelisted pools only accept contributions through participate_with_proof
+        launch_pool.check_whitelist(&user.key(), &[])?;
+
+        require!(
+            total_points >= launch_pool.min_points_to_participate,
+            LaunchpadError::PointsBalanceTooLow
+        );
+        ctx.accounts.global_config.validate_min_points(*pool_points)?;
+
+        require_keys_eq!(vault_info.key(), launch_pool.quote_vault, LaunchpadError::InvalidQuoteVault);
+        let wsol_vault: Account<'info, TokenAccount> = Account::try_from(vault_info)?;
+
+        let (mut user_position, position_bump) = load_or_create_position(
+            position_info,
+            &launch_pool.key(),
+            user,
+            &ctx.accounts.system_program,
+        )?;
+
+        // Clamp to the user's headroom and then the pool's hard cap, charging only the points used
+        let requested_sol = calculate_sol_allowance(*pool_points, launch_pool.points_per_sol)?;
+        let (sol_allowance, points_charged) = clamp_to_contribution_headroom(
+            requested_sol,
+            *pool_points,
+            user_position.contributed_sol,
+            launch_pool.max_contribution_per_user,
+            launch_pool.points_per_sol,
+        )?;
+        let (sol_allowance, points_charged) = clamp_to_contribution_headroom(
+            sol_allowance,
+            points_charged,
+            launch_pool.raised_sol,
+            launch_pool.hard_cap_sol,
+            launch_pool.points_per_sol,
+        )?;
+        require!(sol_allowance > 0, LaunchpadError::InvalidContribution);
+        if sol_allowance < requested_sol {
+            emit!(ContributionClamped {
+                pool: launch_pool.key(),
+                user: user.key(),
+                requested_sol,
+                accepted_sol: sol_allowance,
+                requested_points: *pool_points,
+                points_charged,
+                timestamp: clock.unix_timestamp,
+            });
+        }
+        user_point.validate_lifetime_contribution(sol_allowance, ctx.accounts.global_config.max_lifetime_contribution_sol)?;
+        validate_contribution_amount(
+            sol_allowance,
+            user_position.contributed_sol,
+            launch_pool.min_contribution_per_user,
+            launch_pool.max_contribution_per_user,
+        )?;
+
+        // Transfer SOL to vault
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: user.to_account_info(),
+                    to: wsol_vault.to_account_info(),
+                },
+            ),
+            sol_allowance,
+        )?;
+        token::sync_native(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::SyncNative {
+                account: wsol_vault.to_account_info(),
+            },
+        ))?;
+
+        // Each record advances the nonce, so the next signature is issued against the final value
+        record_participation(
+            &mut launch_pool,
+            user_point,
+            &mut user_position,
+            user.key(),
+            position_bump,
+            sol_allowance,
+            points_charged,
+        )?;
+
+        launch_pool.exit(&crate::ID)?;
+        user_position.exit(&crate::ID)?;
+    }
+
+    msg!("Batch participation across {} pools with {} points", batch_size, aggregate_points);
+
+    Ok(())
+}
+
+/// Load the user's position PDA for a pool, creating it at the user's expense on first participation
+fn load_or_create_position<'info>(
+    position_info: &'info AccountInfo<'info>,
+    launch_pool: &Pubkey,
+    user: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<(Account<'info, UserPosition>, u8)> {
+    let (expected_key, bump) = Pubkey::find_program_address(
+        &[USER_POSITION_SEED, launch_pool.as_ref(), user.key().as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(expected_key, position_info.key(), LaunchpadError::InvalidPosition);
+
+    if position_info.owner == &crate::ID {
+        // Owner and discriminator are checked on deserialization
+        return Ok((Account::try_from(position_info)?, bump));
+    }
+
+    let user_key = user.key();
+    let seeds: &[&[u8]] = &[USER_POSITION_SEED, launch_pool.as_ref(), user_key.as_ref(), &[bump]];
+    let signer_seeds = &[seeds];
+
+    // Top up rent and allocate, tolerating lamports sent to the address ahead of time
+    let required_lamports = Rent::get()?
+        .minimum_balance(UserPosition::SIZE)
+        .saturating_sub(position_info.lamports());
+    if required_lamports > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: user.to_account_info(),
+                    to: position_info.clone(),
+                },
+            ),
+            required_lamports,
+        )?;
+    }
+    anchor_lang::system_program::allocate(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Allocate { account_to_allocate: position_info.clone() },
+            signer_seeds,
+        ),
+        UserPosition::SIZE as u64,
+    )?;
+    anchor_lang::system_program::assign(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            anchor_lang::system_program::Assign { account_to_assign: position_info.clone() },
+            signer_seeds,
+        ),
+        &crate::ID,
+    )?;
+
+    // Freshly allocated data is zeroed, the discriminator is written on exit
+    Ok((Account::try_from_unchecked(position_info)?, bump))
+}
@@ -8,8 +8,8 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount};
 use crate::constants::{LAUNCH_POOL_SEED, *};
 use crate::errors::LaunchpadError;
 use crate::state::{GlobalConfig, LaunchPool, UserPoint, UserPosition};
-use crate::utils::{calculate_sol_allowance, check_time_window, format_points_message, validate_contribution_amount, validate_points_amount, verify_ed25519_ix};
-use crate::events::ParticipationEvent;
+use crate::utils::{calculate_sol_allowance, check_time_window, clamp_to_contribution_headroom, points_payload, validate_contribution_amount, validate_points_amount, verify_authorization, POINTS_DOMAIN};
+use crate::events::{ContributionClamped, ParticipationEvent};
 
 #[derive(Accounts)]
 #[instruction(points_to_use: u64, total_points: u64)]
@@ -93,7 +93,10 @@ pub fn participate_with_points(
     ctx: Context<ParticipateWithPoints>,
     points_to_use: u64,
     total_points: u64,
+    nonce: u64,
     signature: [u8; 64],
+    idempotency_key: Option<u64>,
+    whitelist_proof: Vec<[u8; 32]>,
 ) -> Result<()> {
     let launch_pool = &mut ctx.accounts.launch_pool;
     let user_point = &mut ctx.accounts.user_point;
@@ -102,27 +105,84 @@ pub fn participate_with_points(
     let clock = Clock::get()?;
     user_point.user = user.key();
 
-    // Check launch pool time window
-    check_time_window(launch_pool, clock.unix_timestamp)?;
+    // A retried participation carrying the last accepted key is a no-op
+    if let Some(key) = idempotency_key {
+        require!(key != 0, LaunchpadError::InvalidAmount);
+        if user_position.last_idempotency_key == key {
+            msg!("Participation with idempotency key {} already processed", key);
+            return Ok(());
+        }
+    }
 
-    let message = format_points_message(&user.key(), points_to_use, total_points, &launch_pool.key());
+    // Check launch pool is not paused and within its time window
+    require!(!launch_pool.paused, LaunchpadError::PoolPaused);
+    require!(launch_pool.raised_sol < launch_pool.hard_cap_sol, LaunchpadError::HardCapReached);
+    check_time_window(launch_pool, clock.unix_timestamp)?;
+    launch_pool.check_whitelist(&user.key(), &whitelist_proof)?;
 
-    // Get the current instruction index and load the previous instruction
-    let current_index = load_current_index_checked(&ctx.accounts.instructions_sysvar)?;
-    require!(current_index > 0, LaunchpadError::InvalidInstructionIndex);
-    let ix: Instruction = load_instruction_at_checked((current_index - 1) as usize, &ctx.accounts.instructions_sysvar)?;
+    // Bind the signature to a single use
+    require!(nonce == user_point.participation_nonce, LaunchpadError::InvalidSignature);
+    let payload = points_payload(&user.key(), points_to_use, total_points, &launch_pool.key(), nonce);
 
     // Verify points signature
-    verify_ed25519_ix(&ix, &ctx.accounts.global_config.points_signer.to_bytes(), &message, &signature)?;
+    verify_points_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.global_config.points_signer,
+        POINTS_DOMAIN,
+        &payload,
+        &signature,
+    )?;
 
     // Calculate the amount of SOL user can invest
-    let sol_allowance = calculate_sol_allowance(points_to_use, launch_pool.points_per_sol)?;
+    let requested_sol = calculate_sol_allowance(points_to_use, launch_pool.points_per_sol)?;
 
     // Verify points amount
     validate_points_amount(points_to_use, total_points, user_point.points_consumed)?;
+    require!(
+        total_points >= launch_pool.min_points_to_participate,
+        LaunchpadError::PointsBalanceTooLow
+    );
+    ctx.accounts.global_config.validate_min_points(points_to_use)?;
+
+    // Clamp to the user's remaining headroom, charging only the points actually used
+    let requested_points = points_to_use;
+    let (sol_allowance, points_to_use) = clamp_to_contribution_headroom(
+        requested_sol,
+        requested_points,
+        user_position.contributed_sol,
+        launch_pool.max_contribution_per_user,
+        launch_pool.points_per_sol,
+    )?;
+    // Then to the pool's remaining room under the hard cap
+    let (sol_allowance, points_to_use) = clamp_to_contribution_headroom(
+        sol_allowance,
+        points_to_use,
+        launch_pool.raised_sol,
+        launch_pool.hard_cap_sol,
+        launch_pool.points_per_sol,
+    )?;
+    require!(sol_allowance > 0, LaunchpadError::InvalidContribution);
+    if sol_allowance < requested_sol {
+        emit!(ContributionClamped {
+            pool: launch_pool.key(),
+            user: user.key(),
+            requested_sol,
+            accepted_sol: sol_allowance,
+            requested_points,
+            points_charged: points_to_use,
+            timestamp: clock.unix_timestamp,
+        });
+        msg!("Contribution clamped from {} to {} SOL lamports", requested_sol, sol_allowance);
+    }
+    user_point.validate_lifetime_contribution(sol_allowance, ctx.accounts.global_config.max_lifetime_contribution_sol)?;
 
     // Verify contribution amount
-    validate_contribution_amount(sol_allowance, user_position.contributed_sol)?;
+    validate_contribution_amount(
+        sol_allowance,
+        user_position.contributed_sol,
+        launch_pool.min_contribution_per_user,
+        launch_pool.max_contribution_per_user,
+    )?;
 
     // Transfer SOL to vault
     anchor_lang::system_program::transfer(
@@ -142,8 +202,53 @@ pub fn participate_with_points(
         },
     ))?;
 
+    record_participation(
+        launch_pool,
+        user_point,
+        user_position,
+        user.key(),
+        ctx.bumps.user_position,
+        sol_allowance,
+        points_to_use,
+    )?;
+
+    if let Some(key) = idempotency_key {
+        user_position.last_idempotency_key = key;
+    }
+
+    Ok(())
+}
+
+/// Verify the Ed25519 instruction preceding the current one was signed by points_signer over `{domain}:{payload}`
+pub fn verify_points_signature(
+    instructions_sysvar: &AccountInfo,
+    points_signer: &Pubkey,
+    domain: &str,
+    payload: &str,
+    signature: &[u8; 64],
+) -> Result<()> {
+    // Get the current instruction index and load the previous instruction
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, LaunchpadError::InvalidInstructionIndex);
+    let ix: Instruction = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+
+    verify_authorization(&ix, points_signer, domain, payload, signature)
+}
+
+/// Record a contribution that has already been moved into the pool's WSOL vault
+pub fn record_participation(
+    launch_pool: &mut Account<LaunchPool>,
+    user_point: &mut Account<UserPoint>,
+    user_position: &mut Account<UserPosition>,
+    user: Pubkey,
+    user_position_bump: u8,
+    sol_amount: u64,
+    points_to_use: u64,
+) -> Result<()> {
+    let clock = Clock::get()?;
+
     // 更新发射池状态
-    launch_pool.update_raised_amount(sol_allowance)?;
+    launch_pool.update_raised_amount(sol_amount)?;
     launch_pool.total_points_consumed = launch_pool.total_points_consumed
         .checked_add(points_to_use)
         .ok_or(LaunchpadError::MathOverflow)?;
@@ -156,36 +261,52 @@ pub fn participate_with_points(
             .ok_or(LaunchpadError::MathOverflow)?;
     }
 
+    // Enforce the per-launch participation limit
+    require!(
+        launch_pool.max_participations_per_user == 0
+            || user_position.participation_count < launch_pool.max_participations_per_user,
+        LaunchpadError::TooManyParticipations
+    );
+    user_position.participation_count = user_position.participation_count
+        .checked_add(1)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
     // 更新用户持仓
     if user_position.user == Pubkey::default() {
-        user_position.user = user.key();
+        user_position.user = user;
         user_position.pool = launch_pool.key();
-        user_position.bump = ctx.bumps.user_position;
+        user_position.bump = user_position_bump;
     }
 
     user_position.update_participation(
-        sol_allowance,
+        sol_amount,
         points_to_use,
         clock.unix_timestamp,
     )?;
 
     user_point.points_consumed += points_to_use;
+    user_point.participation_nonce = user_point.participation_nonce
+        .checked_add(1)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    user_point.record_contribution(sol_amount)?;
 
     // Emit participation event
     emit!(ParticipationEvent {
         pool: launch_pool.key(),
-        user: user.key(),
-        sol_amount: sol_allowance,
+        user,
+        sol_amount,
         points_used: points_to_use,
         total_contribution: user_position.contributed_sol,
         pool_raised_total: launch_pool.raised_sol,
         is_first_participation,
         participants_count: launch_pool.participants_count,
+        remaining_to_target: launch_pool.remaining_capacity_sol(),
+        seconds_remaining: launch_pool.end_time.saturating_sub(clock.unix_timestamp).max(0),
         timestamp: clock.unix_timestamp,
     });
 
-    msg!("User {} participated with {} points", user.key(), points_to_use);
-    msg!("SOL contributed: {}", sol_allowance);
+    msg!("User {} participated with {} points", user, points_to_use);
+    msg!("SOL contributed: {}", sol_amount);
     msg!("Total raised: {} / {} SOL",
         launch_pool.raised_sol / LAMPORTS_PER_SOL,
         launch_pool.target_sol / LAMPORTS_PER_SOL
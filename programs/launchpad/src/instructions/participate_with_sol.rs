@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::instructions::record_participation;
+use crate::state::{GlobalConfig, LaunchPool, UserPoint, UserPosition};
+use crate::utils::{check_time_window, validate_contribution_amount};
+
+#[derive(Accounts)]
+pub struct ParticipateWithSol<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: WSOL mint (verified by address)
+    #[account(
+        address = anchor_spl::token::spl_token::native_mint::ID
+    )]
+    pub wsol_mint: Account<'info, Mint>,
+
+    /// Launch pool account, must allow open participation
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_active() @ LaunchpadError::LaunchNotActive,
+        constraint = launch_pool.allow_open_participation @ LaunchpadError::OpenParticipationDisabled,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// User points account, tracks the lifetime contribution
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPoint::SIZE,
+        seeds = [USER_POINT_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub user_point: Box<Account<'info, UserPoint>>,
+
+    /// User position account
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPosition::SIZE,
+        seeds = [USER_POSITION_SEED, launch_pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    /// Launch pool WSOL vault (for storing raised SOL)
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT, launch_pool.key().as_ref(), vault_authority.key().as_ref(), wsol_mint.key().as_ref()],
+        bump,
+        token::mint = wsol_mint,
+        token::authority = vault_authority,
+        token::token_program = token_program
+    )]
+    pub wsol_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Contribute SOL without points or a signature, for public rounds of launches that allow it
+pub fn participate_with_sol(ctx: Context<ParticipateWithSol>, sol_amount: u64) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let user_point = &mut ctx.accounts.user_point;
+    let user_position = &mut ctx.accounts.user_position;
+    let user = &ctx.accounts.user;
+    let clock = Clock::get()?;
+    user_point.user = user.key();
+
+    // Check launch pool is not paused and within its time window
+    require!(!launch_pool.paused, LaunchpadError::PoolPaused);
+    check_time_window(launch_pool, clock.unix_timestamp)?;
+    // Whitelisted pools only accept contributions through participate_with_proof
+    launch_pool.check_whitelist(&user.key(), &[])?;
+
+    // Verify contribution amount
+    launch_pool.validate_hard_cap(sol_amount)?;
+    user_point.validate_lifetime_contribution(sol_amount, ctx.accounts.global_config.max_lifetime_contribution_sol)?;
+    validate_contribution_amount(
+        sol_amount,
+        user_position.contributed_sol,
+        launch_pool.min_contribution_per_user,
+        launch_pool.max_contribution_per_user,
+    )?;
+
+    // Transfer SOL to vault
+    anchor_lang::system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            anchor_lang::system_program::Transfer {
+                from: user.to_account_info(),
+                to: ctx.accounts.wsol_vault.to_account_info(),
+            },
+        ),
+        sol_amount,
+    )?;
+    token::sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        token::SyncNative {
+            account: ctx.accounts.wsol_vault.to_account_info(),
+        },
+    ))?;
+
+    record_participation(
+        launch_pool,
+        user_point,
+        user_position,
+        user.key(),
+        ctx.bumps.user_position,
+        sol_amount,
+        0,
+    )
+}
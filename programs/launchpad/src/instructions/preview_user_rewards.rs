@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LAUNCH_POOL_SEED, USER_POSITION_SEED};
+use crate::instructions::calculate_migrated_rewards;
+use crate::state::{LaunchPool, LaunchStatus, UserPosition};
+
+/// Claimable user rewards returned by preview_user_rewards
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserRewardsPreview {
+    /// Sale tokens claimable now
+    pub tokens_to_claim: u64,
+    /// Excess SOL claimable now
+    pub excess_sol_to_claim: u64,
+    /// SOL refundable from a failed launch, once its refund delay has passed
+    pub refund_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct PreviewUserRewards<'info> {
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    #[account(
+        seeds = [USER_POSITION_SEED, launch_pool.key().as_ref(), user_position.user.as_ref()],
+        bump = user_position.bump,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+}
+
+/// Return what claim_user_rewards would transfer as instruction return data (permissionless, read-only)
+/// Meant to be simulated so clients do not have to reimplement the claim math
+pub fn preview_user_rewards(ctx: Context<PreviewUserRewards>) -> Result<UserRewardsPreview> {
+    let pool = &ctx.accounts.launch_pool;
+    let user_position = &ctx.accounts.user_position;
+    let clock = Clock::get()?;
+
+    let mut preview = UserRewardsPreview {
+        tokens_to_claim: 0,
+        excess_sol_to_claim: 0,
+        refund_amount: 0,
+    };
+
+    // Positions already refunded or with tokens claimed have nothing left through claim_user_rewards
+    if !user_position.tokens_claimed && !user_position.refunded {
+        match pool.status {
+            LaunchStatus::Failed => {
+                preview.refund_amount = user_position.contributed_sol;
+            },
+            LaunchStatus::Migrated => {
+                let (tokens_to_claim, excess_sol_to_claim) =
+                    calculate_migrated_rewards(pool, user_position, clock.unix_timestamp)?;
+                preview.tokens_to_claim = tokens_to_claim;
+                preview.excess_sol_to_claim = excess_sol_to_claim;
+            },
+            _ => {}
+        }
+    }
+
+    msg!(
+        "User rewards preview: {} tokens, {} excess SOL, {} refund",
+        preview.tokens_to_claim,
+        preview.excess_sol_to_claim,
+        preview.refund_amount
+    );
+
+    Ok(preview)
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::GLOBAL_CONFIG_SEED;
+use crate::errors::LaunchpadError;
+use crate::events::AdminProposed;
+use crate::state::GlobalConfig;
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        constraint = global_config.admin == admin.key() @ LaunchpadError::Unauthorized,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+}
+
+/// Propose a new admin, who takes over only after calling accept_admin (admin only)
+/// Proposing again overwrites the pending admin, and Pubkey::default() cancels the proposal
+pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.global_config;
+    let clock = Clock::get()?;
+
+    config.pending_admin = new_admin;
+
+    emit!(AdminProposed {
+        admin: ctx.accounts.admin.key(),
+        pending_admin: new_admin,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Admin transfer proposed to {}", new_admin);
+
+    Ok(())
+}
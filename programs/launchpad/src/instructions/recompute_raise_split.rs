@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED};
+use crate::errors::LaunchpadError;
+use crate::state::{GlobalConfig, LaunchPool};
+
+#[derive(Accounts)]
+pub struct RecomputeRaiseSplit<'info> {
+    #[account(
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Launch pool to inspect, only before migration
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_active() || launch_pool.is_success() @ LaunchpadError::InvalidLaunchStatus,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+}
+
+/// Recompute the liquidity_sol/excess_sol split from raised_sol and target_sol (admin only)
+/// Logs the stored and expected split, and writes the expected split back when `apply` is set
+pub fn recompute_raise_split(ctx: Context<RecomputeRaiseSplit>, apply: bool) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let (liquidity_sol, excess_sol) = launch_pool.expected_raise_split();
+
+    msg!("Raised: {}, target: {}", launch_pool.raised_sol, launch_pool.target_sol);
+    msg!("Stored split: liquidity_sol {}, excess_sol {}", launch_pool.liquidity_sol, launch_pool.excess_sol);
+    msg!("Expected split: liquidity_sol {}, excess_sol {}", liquidity_sol, excess_sol);
+
+    if launch_pool.liquidity_sol == liquidity_sol && launch_pool.excess_sol == excess_sol {
+        msg!("Split is consistent");
+        return Ok(());
+    }
+
+    if apply {
+        launch_pool.liquidity_sol = liquidity_sol;
+        launch_pool.excess_sol = excess_sol;
+        msg!("Split repaired");
+    } else {
+        msg!("Split is out of sync, rerun with apply to repair");
+    }
+
+    Ok(())
+}
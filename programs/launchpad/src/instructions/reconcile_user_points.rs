@@ -0,0 +1,84 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::PointsReconciled;
+use crate::instructions::verify_points_signature;
+use crate::state::{GlobalConfig, UserPoint};
+use crate::utils::{points_reconcile_payload, POINTS_RECONCILE_DOMAIN};
+
+#[derive(Accounts)]
+pub struct ReconcileUserPoints<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: user whose points are reconciled, bound by the points_signer signature
+    pub user: UncheckedAccount<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// User points account
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = UserPoint::SIZE,
+        seeds = [USER_POINT_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub user_point: Box<Account<'info, UserPoint>>,
+
+    /// System variables account for Ed25519 signature verification
+    /// CHECK: This is a system-provided instruction system variable
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Set a user's consumed points to the authoritative value signed by points_signer
+/// The signature is bound to the user's current reconcile nonce so it cannot be replayed
+pub fn reconcile_user_points(
+    ctx: Context<ReconcileUserPoints>,
+    points_consumed: u64,
+    signature: [u8; 64],
+) -> Result<()> {
+    let user_point = &mut ctx.accounts.user_point;
+    let user = ctx.accounts.user.key();
+    let clock = Clock::get()?;
+
+    let nonce = user_point.reconcile_nonce;
+    let payload = points_reconcile_payload(&user, points_consumed, nonce);
+
+    verify_points_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.global_config.points_signer,
+        POINTS_RECONCILE_DOMAIN,
+        &payload,
+        &signature,
+    )?;
+
+    let previous_points_consumed = user_point.points_consumed;
+    user_point.user = user;
+    user_point.points_consumed = points_consumed;
+    user_point.reconcile_nonce = nonce
+        .checked_add(1)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    emit!(PointsReconciled {
+        user,
+        previous_points_consumed,
+        points_consumed,
+        nonce,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Reconciled points for {}: {} -> {}", user, previous_points_consumed, points_consumed);
+
+    Ok(())
+}
@@ -0,0 +1,246 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::state::{GlobalConfig, LaunchPool, UserPoint, UserPosition};
+use crate::utils::{calculate_sol_allowance, check_time_window, points_payload, validate_contribution_amount, validate_points_amount, POINTS_DOMAIN};
+use crate::instructions::{record_participation, verify_points_signature};
+
+#[derive(Accounts)]
+pub struct ReinvestExcess<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: vault authority
+    #[account(
+        mut,
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: WSOL mint (verified by address)
+    #[account(
+        address = anchor_spl::token::spl_token::native_mint::ID
+    )]
+    pub wsol_mint: Account<'info, Mint>,
+
+    /// Migrated launch pool the excess SOL is claimed from
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, source_pool.creator.as_ref(), &source_pool.index.to_le_bytes()],
+        bump = source_pool.bump,
+        constraint = source_pool.is_migrated() @ LaunchpadError::NotMigrated,
+        constraint = !source_pool.excess_converted @ LaunchpadError::ExcessConverted,
+    )]
+    pub source_pool: Box<Account<'info, LaunchPool>>,
+
+    /// User position in the source pool
+    #[account(
+        mut,
+        seeds = [USER_POSITION_SEED, source_pool.key().as_ref(), user.key().as_ref()],
+        bump = source_position.bump,
+        constraint = source_position.contributed_sol > 0 @ LaunchpadError::NothingToClaim,
+        constraint = !source_position.excess_sol_claimed @ LaunchpadError::AlreadyClaimed,
+    )]
+    pub source_position: Box<Account<'info, UserPosition>>,
+
+    /// Source pool's quote vault (SOL)
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = vault_authority,
+        address = source_pool.quote_vault @ LaunchpadError::InvalidQuoteVault,
+    )]
+    pub source_quote_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Active launch pool the excess SOL is contributed to
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_active() @ LaunchpadError::LaunchNotActive,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// User points account
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPoint::SIZE,
+        seeds = [USER_POINT_SEED, user.key().as_ref()],
+        bump,
+    )]
+    pub user_point: Box<Account<'info, UserPoint>>,
+
+    /// User position in the destination pool
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = UserPosition::SIZE,
+        seeds = [USER_POSITION_SEED, launch_pool.key().as_ref(), user.key().as_ref()],
+        bump,
+    )]
+    pub user_position: Box<Account<'info, UserPosition>>,
+
+    /// Destination pool's WSOL vault
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT, launch_pool.key().as_ref(), vault_authority.key().as_ref(), wsol_mint.key().as_ref()],
+        bump,
+        token::mint = wsol_mint,
+        token::authority = vault_authority,
+        token::token_program = token_program
+    )]
+    pub wsol_vault: Box<Account<'info, TokenAccount>>,
+
+    /// User's quote account to receive excess SOL that is not reinvested
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = user,
+    )]
+    pub user_quote_account: Box<Account<'info, TokenAccount>>,
+
+    /// System variables account for Ed25519 signature verification
+    /// CHECK: This is a system-provided instruction system variable
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim excess SOL from a migrated pool and contribute it to an active pool in one step
+/// Any excess beyond the signed points allowance is returned to the user's quote account
+pub fn reinvest_excess(
+    ctx: Context<ReinvestExcess>,
+    points_to_use: u64,
+    total_points: u64,
+    nonce: u64,
+    signature: [u8; 64],
+) -> Result<()> {
+    let source_pool = &mut ctx.accounts.source_pool;
+    let source_position = &mut ctx.accounts.source_position;
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let user_point = &mut ctx.accounts.user_point;
+    let user_position = &mut ctx.accounts.user_position;
+    let user = &ctx.accounts.user;
+    let clock = Clock::get()?;
+    user_point.user = user.key();
+
+    // Respect the source pool's claim window when it gates excess SOL
+    require!(
+        !source_pool.gate_excess_claims || clock.unix_timestamp >= source_pool.claim_start_time,
+        LaunchpadError::ClaimNotOpen
+    );
+
+    // Calculate the user's excess SOL in the source pool
+    let excess_sol = source_position.calculate_excess_sol(source_pool.excess_sol, source_pool.raised_sol)?;
+    require!(excess_sol > 0, LaunchpadError::NothingToClaim);
+
+    // Check destination pool is not paused and within its time window
+    require!(!launch_pool.paused, LaunchpadError::PoolPaused);
+    check_time_window(launch_pool, clock.unix_timestamp)?;
+    // Whitelisted pools only accept contributions through participate_with_proof
+    launch_pool.check_whitelist(&user.key(), &[])?;
+
+    // Bind the signature to a single use
+    require!(nonce == user_point.participation_nonce, LaunchpadError::InvalidSignature);
+    let payload = points_payload(&user.key(), points_to_use, total_points, &launch_pool.key(), nonce);
+
+    // Verify points signature
+    verify_points_signature(
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.global_config.points_signer,
+        POINTS_DOMAIN,
+        &payload,
+        &signature,
+    )?;
+
+    // Calculate the amount of SOL user can invest
+    let sol_allowance = calculate_sol_allowance(points_to_use, launch_pool.points_per_sol)?;
+
+    // Verify points amount and contribution amount against the destination pool
+    validate_points_amount(points_to_use, total_points, user_point.points_consumed)?;
+    require!(
+        total_points >= launch_pool.min_points_to_participate,
+        LaunchpadError::PointsBalanceTooLow
+    );
+    ctx.accounts.global_config.validate_min_points(points_to_use)?;
+    user_point.validate_lifetime_contribution(sol_allowance, ctx.accounts.global_config.max_lifetime_contribution_sol)?;
+    validate_contribution_amount(
+        sol_allowance,
+        user_position.contributed_sol,
+        launch_pool.min_contribution_per_user,
+        launch_pool.max_contribution_per_user,
+    )?;
+
+    launch_pool.validate_hard_cap(sol_allowance)?;
+
+    // The contribution must be fully covered by the claimed excess
+    require!(sol_allowance <= excess_sol, LaunchpadError::InvalidContribution);
+    let remaining_excess = excess_sol
+        .checked_sub(sol_allowance)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    let signer_seeds = vault_signer_seeds(ctx.bumps.vault_authority);
+
+    // Move the contribution between the two pools' WSOL vaults
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.source_quote_vault.to_account_info(),
+                to: ctx.accounts.wsol_vault.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        sol_allowance,
+    )?;
+
+    // Return the rest of the excess to the user
+    if remaining_excess > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.source_quote_vault.to_account_info(),
+                    to: ctx.accounts.user_quote_account.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            remaining_excess,
+        )?;
+    }
+
+    // Mark excess as claimed in the source pool
+    source_position.excess_sol_claimed = true;
+    source_position.last_updated = clock.unix_timestamp;
+    source_pool.record_excess_distributed(excess_sol)?;
+
+    msg!("Reinvesting {} of {} excess SOL from pool {}", sol_allowance, excess_sol, source_pool.key());
+
+    record_participation(
+        launch_pool,
+        user_point,
+        user_position,
+        user.key(),
+        ctx.bumps.user_position,
+        sol_allowance,
+        points_to_use,
+    )
+}
@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::LAUNCH_POOL_SEED;
+use crate::errors::LaunchpadError;
+use crate::events::SymbolReleased;
+use crate::state::{LaunchPool, SymbolRegistry};
+
+#[derive(Accounts)]
+pub struct ReleaseSymbol<'info> {
+    /// Launch pool that registered the symbol, must have failed or been cancelled
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_failed() @ LaunchpadError::InvalidStatus,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// Symbol registry held by the launch pool, closed
+    #[account(
+        mut,
+        constraint = symbol_registry.launch_pool == launch_pool.key() @ LaunchpadError::InvalidLaunchPool,
+    )]
+    pub symbol_registry: Box<Account<'info, SymbolRegistry>>,
+
+    /// Launch creator, receives the registry rent
+    #[account(
+        mut,
+        address = symbol_registry.creator @ LaunchpadError::NotCreator,
+    )]
+    pub creator: SystemAccount<'info>,
+}
+
+/// Free the symbol held by a failed launch so a later launch can register it (permissionless)
+/// Covers pools failed through finalize_launch_batch, which takes no registry accounts
+pub fn release_symbol(ctx: Context<ReleaseSymbol>) -> Result<()> {
+    close_symbol_registry(&ctx.accounts.symbol_registry, &ctx.accounts.creator.to_account_info())
+}
+
+/// Close a symbol registry, returning its rent to the launch creator
+pub fn close_symbol_registry<'info>(
+    symbol_registry: &Account<'info, SymbolRegistry>,
+    creator: &AccountInfo<'info>,
+) -> Result<()> {
+    symbol_registry.close(creator.clone())?;
+
+    emit!(SymbolReleased {
+        pool: symbol_registry.launch_pool,
+        symbol_hash: symbol_registry.symbol_hash,
+        creator: creator.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Released symbol registry {}", symbol_registry.key());
+
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED};
+use crate::errors::LaunchpadError;
+use crate::events::CreatorFeeRecipientSet;
+use crate::state::{GlobalConfig, LaunchPool};
+
+#[derive(Accounts)]
+pub struct SetCreatorFeeRecipient<'info> {
+    #[account(
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Launch pool account, must be migrated
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_migrated() @ LaunchpadError::NotMigrated,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+}
+
+/// Redirect the creator's pool fee share to another account, or reset it with None (admin only)
+pub fn set_creator_fee_recipient(
+    ctx: Context<SetCreatorFeeRecipient>,
+    recipient: Option<Pubkey>,
+) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let clock = Clock::get()?;
+
+    launch_pool.creator_fee_recipient_override = recipient;
+
+    emit!(CreatorFeeRecipientSet {
+        pool: launch_pool.key(),
+        creator: launch_pool.creator,
+        recipient: launch_pool.creator_fee_recipient(),
+        is_override: recipient.is_some(),
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Creator fee recipient set to {}", launch_pool.creator_fee_recipient());
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED};
+use crate::errors::LaunchpadError;
+use crate::events::PoolPausedSet;
+use crate::state::{GlobalConfig, LaunchPool};
+
+#[derive(Accounts)]
+pub struct SetPoolPaused<'info> {
+    #[account(
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Launch pool account
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+}
+
+/// Pause or resume contributions to a single pool (admin only)
+/// Finalization and claims are unaffected
+pub fn set_pool_paused(ctx: Context<SetPoolPaused>, paused: bool) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let clock = Clock::get()?;
+
+    launch_pool.paused = paused;
+
+    emit!(PoolPausedSet {
+        pool: launch_pool.key(),
+        paused,
+        admin: ctx.accounts.admin.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Launch pool {} paused: {}", launch_pool.key(), paused);
+
+    Ok(())
+}
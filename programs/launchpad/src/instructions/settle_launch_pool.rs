@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::constants::{GLOBAL_CONFIG_SEED, LAUNCH_POOL_SEED};
+use crate::errors::LaunchpadError;
+use crate::events::LaunchPoolSettled;
+use crate::state::{GlobalConfig, LaunchPool};
+
+#[derive(Accounts)]
+pub struct SettleLaunchPool<'info> {
+    /// Global configuration account
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// Launch pool account, must be terminal and not settled yet
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = !launch_pool.settled @ LaunchpadError::AlreadyClaimed,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// Pool's quote vault, only needed for failed pools whose refunds predate refund tracking
+    #[account(address = launch_pool.quote_vault @ LaunchpadError::InvalidQuoteVault)]
+    pub pool_quote_vault: Option<Box<Account<'info, TokenAccount>>>,
+}
+
+/// Stop counting a migrated pool, or a failed pool with every refund paid, against the global config (permissionless)
+/// close_config only succeeds once every pool created under the config has been settled
+pub fn settle_launch_pool(ctx: Context<SettleLaunchPool>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let quote_vault_amount = ctx.accounts.pool_quote_vault.as_ref().map(|vault| vault.amount);
+    require!(launch_pool.can_settle(quote_vault_amount), LaunchpadError::LaunchesNotTerminal);
+
+    launch_pool.settled = true;
+    let global_config = &mut ctx.accounts.global_config;
+    global_config.record_pool_settled()?;
+
+    emit!(LaunchPoolSettled {
+        pool: launch_pool.key(),
+        status: launch_pool.status as u8,
+        total_refunded: launch_pool.total_refunded,
+        active_pool_count: global_config.active_pool_count,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Launch pool {} settled, {} still active", launch_pool.key(), global_config.active_pool_count);
+
+    Ok(())
+}
@@ -4,10 +4,10 @@ use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 use crate::constants::{TOKEN_VAULT, VAULT_AUTHORITY};
 use crate::errors::LaunchpadError;
 use crate::events::{TokensStaked};
-use crate::state::{GlobalConfig, StakingPosition};
+use crate::state::{GlobalConfig, RewardEpoch, StakingRewardConfig, StakingCounter, StakingPosition};
 
 #[derive(Accounts)]
-#[instruction(amount: u64, lock_duration: i64)]
+#[instruction(params: StakeTokensParams)]
 pub struct StakeTokens<'info> {
     /// User who wants to stake tokens
     #[account(mut)]
@@ -52,7 +52,21 @@ pub struct StakeTokens<'info> {
     )]
     pub token_vault: Box<Account<'info, TokenAccount>>,
 
-    /// Staking position account for this user and token
+    /// Counter assigning position indexes for this user and token
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = StakingCounter::SIZE,
+        seeds = [
+            StakingCounter::SEED,
+            user.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump,
+    )]
+    pub staking_counter: Box<Account<'info, StakingCounter>>,
+
+    /// Staking position account for this user, token and position index
     #[account(
         init_if_needed,
         payer = user,
@@ -60,12 +74,28 @@ pub struct StakeTokens<'info> {
         seeds = [
             StakingPosition::SEED,
             user.key().as_ref(),
-            token_mint.key().as_ref()
+            token_mint.key().as_ref(),
+            &params.position_index.to_le_bytes()
         ],
         bump,
     )]
     pub staking_position: Box<Account<'info, StakingPosition>>,
 
+    /// Current reward epoch for the token, if one has been opened
+    #[account(
+        seeds = [RewardEpoch::SEED, token_mint.key().as_ref()],
+        bump = reward_epoch.bump,
+    )]
+    pub reward_epoch: Option<Account<'info, RewardEpoch>>,
+
+    /// Reward accumulator for the token, if staking rewards are configured
+    #[account(
+        mut,
+        seeds = [StakingRewardConfig::SEED, token_mint.key().as_ref()],
+        bump = staking_reward_config.bump,
+    )]
+    pub staking_reward_config: Option<Account<'info, StakingRewardConfig>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
 
@@ -80,13 +110,15 @@ pub struct StakeTokens<'info> {
 pub struct StakeTokensParams {
     pub amount: u64,
     pub lock_duration: i64,
+    /// Existing position to add to, or the counter's next index to open a new one
+    pub position_index: u64,
 }
 
 pub fn stake_tokens(
     ctx: Context<StakeTokens>,
     params: StakeTokensParams,
 ) -> Result<()> {
-    let StakeTokensParams { amount, lock_duration } = params;
+    let StakeTokensParams { amount, lock_duration, position_index } = params;
 
     // Validate inputs
     require!(amount > 0, LaunchpadError::CannotStakeZeroTokens);
@@ -96,6 +128,7 @@ pub fn stake_tokens(
 
     // Validate staking parameters
     global_config.validate_stake_params(lock_duration)?;
+    global_config.validate_min_stake(amount, ctx.accounts.token_mint.decimals)?;
 
     // Transfer tokens from user to vault
     let transfer_ctx = CpiContext::new(
@@ -115,6 +148,14 @@ pub fn stake_tokens(
     let bump = ctx.bumps.staking_position;
 
     if is_new_position {
+        // New positions must take the next free index so indexes stay dense
+        let assigned_index = ctx.accounts.staking_counter.next_index(
+            ctx.accounts.user.key(),
+            ctx.accounts.token_mint.key(),
+            ctx.bumps.staking_counter,
+        )?;
+        require!(position_index == assigned_index, LaunchpadError::InvalidPosition);
+
         // Initialize new staking position
         ctx.accounts.staking_position.initialize(
             ctx.accounts.user.key(),
@@ -124,6 +165,7 @@ pub fn stake_tokens(
             current_time,
             bump,
         )?;
+        ctx.accounts.staking_position.position_index = position_index;
     } else {
         // Update existing staking position (add to existing stake)
         ctx.accounts.staking_position.update_stake(
@@ -133,6 +175,12 @@ pub fn stake_tokens(
         )?;
     }
 
+    // Settle rewards earned so far and enroll the new stake amount
+    if let Some(config) = ctx.accounts.staking_reward_config.as_mut() {
+        config.update_rewards(current_time)?;
+        config.settle_position(&mut ctx.accounts.staking_position)?;
+    }
+
     // Emit stake event (without reward fields as rewards are handled off-chain)
     emit!(TokensStaked {
         user: ctx.accounts.user.key(),
@@ -144,14 +192,16 @@ pub fn stake_tokens(
         unlock_time: ctx.accounts.staking_position.unlock_time,
         stake_time: current_time,
         is_additional_stake: !is_new_position,
+        epoch_index: ctx.accounts.reward_epoch.as_ref().map_or(0, |epoch| epoch.epoch_index),
     });
 
     msg!(
-        "User {} staked {} tokens of mint {} for {} seconds",
+        "User {} staked {} tokens of mint {} for {} seconds in position {}",
         ctx.accounts.user.key(),
         amount,
         ctx.accounts.token_mint.key(),
-        lock_duration
+        lock_duration,
+        position_index
     );
 
     Ok(())
@@ -1,4 +1,4 @@
-use crate::{constants::GLOBAL_CONFIG_SEED, dlmm::{self, types::RemainingAccountsInfo}, errors::LaunchpadError, events::SwapFeeCharged, state::GlobalConfig};
+use crate::{constants::{GLOBAL_CONFIG_SEED, MAX_BASIS_POINT}, dlmm::{self, types::RemainingAccountsInfo}, errors::LaunchpadError, events::SwapFeeCharged, state::GlobalConfig};
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Transfer};
 
@@ -13,7 +13,6 @@ pub struct DlmmSwap<'info> {
 
     #[account(
         mut,
-        constraint = admin_fee_token_in.owner == global_config.admin,
         constraint = admin_fee_token_in.mint == anchor_spl::token::spl_token::native_mint::ID
     )]
     pub admin_fee_token_in: Box<Account<'info, TokenAccount>>,
@@ -36,14 +35,11 @@ pub struct DlmmSwap<'info> {
     /// CHECK: Reserve account of token Y
     pub reserve_y: UncheckedAccount<'info>,
 
-    #[account(
-        mut,
-        constraint = user_token_in.mint == anchor_spl::token::spl_token::native_mint::ID @ crate::errors::LaunchpadError::InvalidTokenMint
-    )]
-    /// User token account to sell token (must be WSOL)
+    #[account(mut)]
+    /// User token account to sell token (must be WSOL for swap, the project token for swap_sell)
     pub user_token_in: Box<Account<'info, TokenAccount>>,
     #[account(mut)]
-    /// User token account to buy token
+    /// User token account to buy token (must be WSOL for swap_sell)
     pub user_token_out: Box<Account<'info, TokenAccount>>,
 
     /// CHECK: Mint account of token X
@@ -103,10 +99,17 @@ pub fn handle_dlmm_swap<'a, 'b, 'c, 'info>(
     min_amount_out: u64,
     remaining_accounts_info: RemainingAccountsInfo
 ) -> Result<()> {
-    // Calculate 0.05% fee from input tokens (5 basis points)
+    require!(
+        ctx.accounts.user_token_in.mint == anchor_spl::token::spl_token::native_mint::ID,
+        LaunchpadError::InvalidTokenMint
+    );
+    ctx.accounts.validate_fee_account()?;
+
+    // Calculate the configured fee from input tokens
+    let swap_fee_bps = ctx.accounts.global_config.swap_fee_bps;
     let fee_amount = amount_in
-        .checked_mul(5)
-        .and_then(|v| v.checked_div(10000))
+        .checked_mul(swap_fee_bps as u64)
+        .and_then(|v| v.checked_div(MAX_BASIS_POINT))
         .ok_or(LaunchpadError::MathOverflow)?;
 
     // Calculate actual amount to swap after deducting fee
@@ -130,50 +133,12 @@ pub fn handle_dlmm_swap<'a, 'b, 'c, 'info>(
         )?;
     }
 
-    // Record user's output token balance before swap
-    let balance_before = ctx.accounts.user_token_out.amount;
-
-    // Execute direct DLMM swap with actual swap amount (after fee deduction)
-    let accounts = dlmm::cpi::accounts::Swap2 {
-        lb_pair: ctx.accounts.lb_pair.to_account_info(),
-        bin_array_bitmap_extension: ctx
-            .accounts
-            .bin_array_bitmap_extension
-            .as_ref()
-            .map(|account| account.to_account_info()),
-        reserve_x: ctx.accounts.reserve_x.to_account_info(),
-        reserve_y: ctx.accounts.reserve_y.to_account_info(),
-        user_token_in: ctx.accounts.user_token_in.to_account_info(),
-        user_token_out: ctx.accounts.user_token_out.to_account_info(),
-        token_x_mint: ctx.accounts.token_x_mint.to_account_info(),
-        token_y_mint: ctx.accounts.token_y_mint.to_account_info(),
-        oracle: ctx.accounts.oracle.to_account_info(),
-        host_fee_in: ctx
-            .accounts
-            .host_fee_in
-            .as_ref()
-            .map(|account| account.to_account_info()),
-        user: ctx.accounts.user.to_account_info(),
-        token_x_program: ctx.accounts.token_x_program.to_account_info(),
-        token_y_program: ctx.accounts.token_y_program.to_account_info(),
-        memo_program: ctx.accounts.memo_program.to_account_info(),
-        event_authority: ctx.accounts.event_authority.to_account_info(),
-        program: ctx.accounts.dlmm_program.to_account_info(),
-    };
-
-    // Direct CPI call without signer_seeds - user signs for themselves
-    let cpi_context = CpiContext::new(ctx.accounts.dlmm_program.to_account_info(), accounts)
-        .with_remaining_accounts(ctx.remaining_accounts.to_vec());
-    dlmm::cpi::swap2(cpi_context, actual_swap_amount, min_amount_out, remaining_accounts_info)?;
-
-    // Reload user's output token account to get updated balance
-    ctx.accounts.user_token_out.reload()?;
-
-    // Calculate the amount of tokens received from swap
-    let balance_after = ctx.accounts.user_token_out.amount;
-    let output_amount = balance_after
-        .checked_sub(balance_before)
-        .ok_or(LaunchpadError::MathOverflow)?;
+    let output_amount = ctx.accounts.swap_via_dlmm(
+        ctx.remaining_accounts,
+        actual_swap_amount,
+        min_amount_out,
+        remaining_accounts_info,
+    )?;
 
     // Emit swap fee event
     emit!(SwapFeeCharged {
@@ -184,10 +149,153 @@ pub fn handle_dlmm_swap<'a, 'b, 'c, 'info>(
         fee_amount,
         actual_swap_amount: actual_swap_amount, // Amount actually swapped after fee deduction
         amount_out: output_amount,
-        fee_percentage: 5, // 0.05% represented as basis points
+        fee_percentage: swap_fee_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+
+}
+
+/// Sells the project token for WSOL through DLMM, charging the fee on the WSOL output
+///
+/// # Arguments
+///
+/// * `ctx` - The context containing accounts and programs.
+/// * `amount_in` - The amount of project tokens to be swapped.
+/// * `min_amount_out` - The minimum WSOL output expected from DLMM before the fee a.k.a slippage
+///
+/// # Returns
+///
+/// Returns a `Result` indicating success or failure.
+pub fn handle_dlmm_swap_sell<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DlmmSwap<'info>>,
+    amount_in: u64,
+    min_amount_out: u64,
+    remaining_accounts_info: RemainingAccountsInfo
+) -> Result<()> {
+    require!(
+        ctx.accounts.user_token_out.mint == anchor_spl::token::spl_token::native_mint::ID,
+        LaunchpadError::InvalidTokenMint
+    );
+    ctx.accounts.validate_fee_account()?;
+
+    // Swap the full input, the fee comes out of the WSOL received
+    let output_amount = ctx.accounts.swap_via_dlmm(
+        ctx.remaining_accounts,
+        amount_in,
+        min_amount_out,
+        remaining_accounts_info,
+    )?;
+
+    let swap_fee_bps = ctx.accounts.global_config.swap_fee_bps;
+    let fee_amount = output_amount
+        .checked_mul(swap_fee_bps as u64)
+        .and_then(|v| v.checked_div(MAX_BASIS_POINT))
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    // Transfer fee from user's WSOL output account to admin fee account
+    if fee_amount > 0 {
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_x_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token_out.to_account_info(),
+                    to: ctx.accounts.admin_fee_token_in.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            fee_amount,
+        )?;
+    }
+
+    emit!(SwapFeeCharged {
+        user: ctx.accounts.user.key(),
+        input_token_mint: ctx.accounts.user_token_in.mint,
+        output_token_mint: ctx.accounts.user_token_out.mint,
+        amount_in,
+        fee_amount,
+        actual_swap_amount: amount_in,
+        amount_out: output_amount
+            .checked_sub(fee_amount)
+            .ok_or(LaunchpadError::MathOverflow)?,
+        fee_percentage: swap_fee_bps,
         timestamp: Clock::get()?.unix_timestamp,
     });
 
     Ok(())
+}
+
+impl<'info> DlmmSwap<'info> {
+    /// Reject fee accounts that are stale after a swap_fee_account rotation
+    fn validate_fee_account(&self) -> Result<()> {
+        require!(
+            self.global_config.is_swap_fee_account(
+                &self.admin_fee_token_in.key(),
+                &self.admin_fee_token_in.owner,
+            ),
+            LaunchpadError::InvalidFeeAccount
+        );
+
+        Ok(())
+    }
 
+    /// Execute the DLMM swap signed by the user and return the output amount received
+    fn swap_via_dlmm(
+        &mut self,
+        remaining_accounts: &[AccountInfo<'info>],
+        amount_in: u64,
+        min_amount_out: u64,
+        remaining_accounts_info: RemainingAccountsInfo,
+    ) -> Result<u64> {
+        // Record user's output token balance before swap
+        let balance_before = self.user_token_out.amount;
+
+        let accounts = dlmm::cpi::accounts::Swap2 {
+            lb_pair: self.lb_pair.to_account_info(),
+            bin_array_bitmap_extension: self
+                .bin_array_bitmap_extension
+                .as_ref()
+                .map(|account| account.to_account_info()),
+            reserve_x: self.reserve_x.to_account_info(),
+            reserve_y: self.reserve_y.to_account_info(),
+            user_token_in: self.user_token_in.to_account_info(),
+            user_token_out: self.user_token_out.to_account_info(),
+            token_x_mint: self.token_x_mint.to_account_info(),
+            token_y_mint: self.token_y_mint.to_account_info(),
+            oracle: self.oracle.to_account_info(),
+            host_fee_in: self
+                .host_fee_in
+                .as_ref()
+                .map(|account| account.to_account_info()),
+            user: self.user.to_account_info(),
+            token_x_program: self.token_x_program.to_account_info(),
+            token_y_program: self.token_y_program.to_account_info(),
+            memo_program: self.memo_program.to_account_info(),
+            event_authority: self.event_authority.to_account_info(),
+            program: self.dlmm_program.to_account_info(),
+        };
+
+        // Direct CPI call without signer_seeds - user signs for themselves
+        let cpi_context = CpiContext::new(self.dlmm_program.to_account_info(), accounts)
+            .with_remaining_accounts(remaining_accounts.to_vec());
+        dlmm::cpi::swap2(cpi_context, amount_in, min_amount_out, remaining_accounts_info)?;
+
+        // Reload user's output token account to get updated balance
+        self.user_token_out.reload()?;
+
+        // Calculate the amount of tokens received from swap
+        let output_amount = self.user_token_out.amount
+            .checked_sub(balance_before)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
+        // Enforce slippage here too rather than relying only on DLMM, the whole swap reverts with any fee charged
+        // A zero output never earns the fee, even when min_amount_out allows it
+        require!(
+            output_amount > 0 && output_amount >= min_amount_out,
+            LaunchpadError::SlippageExceeded
+        );
+
+        Ok(output_amount)
+    }
 }
@@ -0,0 +1,154 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::ExcessDustSwept;
+use crate::state::{GlobalConfig, LaunchPool};
+
+#[derive(Accounts)]
+pub struct SweepExcessDust<'info> {
+    #[account(
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// CHECK: WSOL mint (verified by address)
+    #[account(
+        address = anchor_spl::token::spl_token::native_mint::ID
+    )]
+    pub wsol_mint: Account<'info, Mint>,
+
+    /// Launch pool account, must be migrated with every excess share settled
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_migrated() @ LaunchpadError::NotMigrated,
+        constraint = !launch_pool.excess_converted @ LaunchpadError::ExcessConverted,
+        constraint = !launch_pool.excess_dust_swept @ LaunchpadError::AlreadyClaimed,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// Pool's quote vault (SOL)
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = vault_authority,
+        address = launch_pool.quote_vault @ LaunchpadError::InvalidQuoteVault,
+    )]
+    pub pool_quote_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Treasury WSOL account owned by the admin
+    #[account(
+        mut,
+        token::mint = wsol_mint,
+        token::authority = global_config.admin,
+    )]
+    pub treasury_quote_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Sweep the excess SOL left over from rounding once every participant has settled their share (admin only)
+pub fn sweep_excess_dust(ctx: Context<SweepExcessDust>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let clock = Clock::get()?;
+
+    // Claimable excess remains while any position has not settled
+    require!(
+        launch_pool.excess_claim_count >= launch_pool.participants_count,
+        LaunchpadError::ExcessStillClaimable
+    );
+
+    let dust = excess_dust(launch_pool)?;
+
+    if dust > 0 {
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.pool_quote_vault.to_account_info(),
+                    to: ctx.accounts.treasury_quote_account.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                vault_signer_seeds(ctx.bumps.vault_authority),
+            ),
+            dust,
+        )?;
+    }
+
+    // The whole excess is now accounted for
+    launch_pool.excess_sol_distributed = launch_pool.excess_sol;
+    launch_pool.excess_dust_swept = true;
+
+    emit!(ExcessDustSwept {
+        pool: launch_pool.key(),
+        amount: dust,
+        excess_sol: launch_pool.excess_sol,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Swept {} lamports of excess dust from pool {}", dust, launch_pool.key());
+
+    Ok(())
+}
+
+/// Excess SOL left in the quote vault after every settled share, the rounding remainder
+fn excess_dust(launch_pool: &LaunchPool) -> Result<u64> {
+    let dust = launch_pool.excess_sol
+        .checked_sub(launch_pool.excess_sol_distributed)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    Ok(dust)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{LaunchStatus, UserPosition};
+
+    fn position(contributed_sol: u64) -> UserPosition {
+        let mut data = [0u8; UserPosition::SIZE];
+        data[..8].copy_from_slice(UserPosition::DISCRIMINATOR);
+        let mut position = UserPosition::try_deserialize(&mut &data[..]).unwrap();
+        position.contributed_sol = contributed_sol;
+        position
+    }
+
+    #[test]
+    fn dust_is_excess_minus_distributed_shares() {
+        let mut pool = LaunchPool::test_pool(LaunchStatus::Migrated);
+        pool.raised_sol = 3_000;
+        pool.excess_sol = 1_000;
+
+        let mut distributed = 0;
+        for contributed_sol in [1_000, 1_300, 700] {
+            let share = position(contributed_sol)
+                .calculate_excess_sol(pool.excess_sol, pool.raised_sol)
+                .unwrap();
+            pool.record_excess_distributed(share).unwrap();
+            distributed += share;
+        }
+
+        // 333 + 433 + 233 rounds down to 999
+        assert_eq!(distributed, 999);
+        assert_eq!(excess_dust(&pool).unwrap(), pool.excess_sol - distributed);
+        assert_eq!(excess_dust(&pool).unwrap(), 1);
+    }
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, TokenAccount, TokenInterface, TransferChecked};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::*;
+use crate::errors::LaunchpadError;
+use crate::events::UnsoldTokensSwept;
+use crate::state::{GlobalConfig, LaunchPool};
+
+#[derive(Accounts)]
+pub struct SweepUnsoldTokens<'info> {
+    #[account(
+        constraint = admin.key() == global_config.admin @ LaunchpadError::Unauthorized
+    )]
+    pub admin: Signer<'info>,
+
+    /// Global configuration account
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    pub global_config: Box<Account<'info, GlobalConfig>>,
+
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Launch pool account, must be migrated and not swept yet
+    #[account(
+        mut,
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+        constraint = launch_pool.is_migrated() @ LaunchpadError::NotMigrated,
+        constraint = !launch_pool.unsold_tokens_swept @ LaunchpadError::AlreadyClaimed,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// Launch token mint
+    #[account(
+        address = launch_pool.token_mint @ LaunchpadError::InvalidTokenMint,
+        mint::token_program = token_program,
+    )]
+    pub token_mint: Box<InterfaceAccount<'info, Mint>>,
+
+    /// Pool's token vault
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = vault_authority,
+        token::token_program = token_program,
+        address = launch_pool.token_vault @ LaunchpadError::InvalidTokenVault,
+    )]
+    pub pool_token_vault: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Treasury token account owned by the admin
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = global_config.admin,
+        token::token_program = token_program,
+    )]
+    pub treasury_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Token program of the launch token, classic SPL Token or Token-2022
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Sweep the sale tokens participants have not claimed once the grace period has passed (admin only)
+/// Participants who have not claimed their tokens by then forfeit them, excess SOL stays claimable
+pub fn sweep_unsold_tokens(ctx: Context<SweepUnsoldTokens>) -> Result<()> {
+    let launch_pool = &mut ctx.accounts.launch_pool;
+    let clock = Clock::get()?;
+
+    // The grace period runs from whichever is later, finalization or the claim window opening
+    let sweep_open_time = launch_pool.finalized_time
+        .max(launch_pool.claim_start_time)
+        .checked_add(UNSOLD_TOKEN_SWEEP_DELAY)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    require!(clock.unix_timestamp >= sweep_open_time, LaunchpadError::ClaimNotOpen);
+
+    let amount = launch_pool.sale_allocation
+        .checked_sub(launch_pool.total_tokens_claimed)
+        .ok_or(LaunchpadError::MathOverflow)?;
+
+    if amount > 0 {
+        token_interface::transfer_checked(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.pool_token_vault.to_account_info(),
+                    mint: ctx.accounts.token_mint.to_account_info(),
+                    to: ctx.accounts.treasury_token_account.to_account_info(),
+                    authority: ctx.accounts.vault_authority.to_account_info(),
+                },
+                vault_signer_seeds(ctx.bumps.vault_authority),
+            ),
+            amount,
+            ctx.accounts.token_mint.decimals,
+        )?;
+    }
+
+    launch_pool.unsold_tokens_swept = true;
+
+    emit!(UnsoldTokensSwept {
+        pool: launch_pool.key(),
+        token_mint: launch_pool.token_mint,
+        amount,
+        total_tokens_claimed: launch_pool.total_tokens_claimed,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Swept {} unclaimed sale tokens from pool {}", amount, launch_pool.key());
+
+    Ok(())
+}
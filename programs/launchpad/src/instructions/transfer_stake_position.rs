@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+
+use crate::errors::LaunchpadError;
+use crate::events::StakePositionTransferred;
+use crate::state::{StakingCounter, StakingPosition};
+
+#[derive(Accounts)]
+#[instruction(position_index: u64)]
+pub struct TransferStakePosition<'info> {
+    /// Current owner of the staking position
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Wallet receiving the staking position
+    #[account(
+        constraint = new_owner.key() != user.key() @ LaunchpadError::InvalidStakeTransfer,
+    )]
+    pub new_owner: Signer<'info>,
+
+    /// Token mint of the staked token
+    pub token_mint: Account<'info, Mint>,
+
+    /// Existing staking position, closed and replaced by the new owner's position
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            StakingPosition::SEED,
+            user.key().as_ref(),
+            token_mint.key().as_ref(),
+            &position_index.to_le_bytes()
+        ],
+        bump = staking_position.bump,
+        constraint = staking_position.user == user.key() @ LaunchpadError::NoStakeFound,
+        constraint = staking_position.token_mint == token_mint.key() @ LaunchpadError::InvalidStakingTokenMint,
+    )]
+    pub staking_position: Account<'info, StakingPosition>,
+
+    /// New owner's position counter, the moved position takes its next index
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = StakingCounter::SIZE,
+        seeds = [
+            StakingCounter::SEED,
+            new_owner.key().as_ref(),
+            token_mint.key().as_ref()
+        ],
+        bump,
+    )]
+    pub new_owner_counter: Account<'info, StakingCounter>,
+
+    /// New owner's staking position, must not already exist
+    #[account(
+        init,
+        payer = user,
+        space = StakingPosition::SIZE,
+        seeds = [
+            StakingPosition::SEED,
+            new_owner.key().as_ref(),
+            token_mint.key().as_ref(),
+            &new_owner_counter.next_position_index.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub new_staking_position: Account<'info, StakingPosition>,
+
+    /// System program
+    pub system_program: Program<'info, System>,
+}
+
+/// Move a staking position to another wallet, keeping its amount and lock schedule
+/// Both the current and the new owner must sign
+pub fn transfer_stake_position(ctx: Context<TransferStakePosition>, _position_index: u64) -> Result<()> {
+    let staking_position = &ctx.accounts.staking_position;
+    let new_staking_position = &mut ctx.accounts.new_staking_position;
+    let current_time = Clock::get()?.unix_timestamp;
+
+    let position_index = ctx.accounts.new_owner_counter.next_index(
+        ctx.accounts.new_owner.key(),
+        staking_position.token_mint,
+        ctx.bumps.new_owner_counter,
+    )?;
+    new_staking_position.move_from(
+        staking_position,
+        ctx.accounts.new_owner.key(),
+        position_index,
+        ctx.bumps.new_staking_position,
+    );
+
+    emit!(StakePositionTransferred {
+        from: ctx.accounts.user.key(),
+        to: ctx.accounts.new_owner.key(),
+        old_position: staking_position.key(),
+        new_position: new_staking_position.key(),
+        token_mint: staking_position.token_mint,
+        staked_amount: staking_position.staked_amount,
+        unlock_time: staking_position.unlock_time,
+        transfer_time: current_time,
+    });
+
+    msg!(
+        "Staking position of {} tokens moved from {} to {}",
+        staking_position.staked_amount,
+        ctx.accounts.user.key(),
+        ctx.accounts.new_owner.key()
+    );
+
+    // Old position account is automatically closed by the 'close = user' constraint
+
+    Ok(())
+}
@@ -1,13 +1,14 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer};
 
-use crate::const_pda::const_authority::VAULT_BUMP;
+use crate::const_pda::const_authority::vault_signer_seeds;
 use crate::constants::{TOKEN_VAULT, VAULT_AUTHORITY};
 use crate::errors::LaunchpadError;
-use crate::events::{TokensUnstaked};
-use crate::state::{GlobalConfig, StakingPosition};
+use crate::events::{StakingRewardsClaimed, TokensUnstaked};
+use crate::state::{GlobalConfig, RewardEpoch, StakingRewardConfig, StakingPosition};
 
 #[derive(Accounts)]
+#[instruction(position_index: u64)]
 pub struct UnstakeTokens<'info> {
     /// User who wants to unstake tokens
     #[account(mut)]
@@ -58,7 +59,8 @@ pub struct UnstakeTokens<'info> {
         seeds = [
             StakingPosition::SEED,
             user.key().as_ref(),
-            token_mint.key().as_ref()
+            token_mint.key().as_ref(),
+            &position_index.to_le_bytes()
         ],
         bump = staking_position.bump,
         constraint = staking_position.user == user.key() @ LaunchpadError::NoStakeFound,
@@ -66,6 +68,32 @@ pub struct UnstakeTokens<'info> {
     )]
     pub staking_position: Account<'info, StakingPosition>,
 
+    /// Current reward epoch for the token, if one has been opened
+    #[account(
+        seeds = [RewardEpoch::SEED, token_mint.key().as_ref()],
+        bump = reward_epoch.bump,
+    )]
+    pub reward_epoch: Option<Account<'info, RewardEpoch>>,
+
+    /// Reward accumulator for the token, if staking rewards are configured
+    #[account(
+        mut,
+        seeds = [StakingRewardConfig::SEED, token_mint.key().as_ref()],
+        bump = staking_reward_config.bump,
+    )]
+    pub staking_reward_config: Option<Account<'info, StakingRewardConfig>>,
+
+    /// Treasury-funded reward vault, required when the position has rewards to pay out
+    #[account(mut)]
+    pub reward_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// User's account receiving the rewards, required when the position has rewards to pay out
+    #[account(
+        mut,
+        token::authority = user,
+    )]
+    pub user_reward_account: Option<Box<Account<'info, TokenAccount>>>,
+
     /// Token program
     pub token_program: Program<'info, Token>,
 
@@ -73,13 +101,70 @@ pub struct UnstakeTokens<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
-    let staking_position = &ctx.accounts.staking_position;
+pub fn unstake_tokens(ctx: Context<UnstakeTokens>, position_index: u64) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp;
 
+    // Closing the position pays out its rewards as far as the vault allows, principal is never held back
+    match ctx.accounts.staking_reward_config.as_mut() {
+        Some(config) => {
+            config.update_rewards(current_time)?;
+            config.settle_position(&mut ctx.accounts.staking_position)?;
+            config.withdraw_position(&mut ctx.accounts.staking_position)?;
+
+            let pending = ctx.accounts.staking_position.pending_rewards;
+            if pending > 0 {
+                let (Some(reward_vault), Some(user_reward_account)) = (
+                    ctx.accounts.reward_vault.as_ref(),
+                    ctx.accounts.user_reward_account.as_ref(),
+                ) else {
+                    return err!(LaunchpadError::StakingRewardAccountsRequired);
+                };
+                let (expected_vault, _) = Pubkey::find_program_address(
+                    &[StakingRewardConfig::VAULT_SEED, config.key().as_ref()],
+                    &crate::ID,
+                );
+                require_keys_eq!(reward_vault.key(), expected_vault, LaunchpadError::InvalidTokenVault);
+                require_keys_eq!(user_reward_account.mint, config.reward_mint, LaunchpadError::InvalidTokenMint);
+
+                let paid = pending.min(reward_vault.amount);
+                if paid > 0 {
+                    token::transfer(
+                        CpiContext::new_with_signer(
+                            ctx.accounts.token_program.to_account_info(),
+                            Transfer {
+                                from: reward_vault.to_account_info(),
+                                to: user_reward_account.to_account_info(),
+                                authority: ctx.accounts.vault_authority.to_account_info(),
+                            },
+                            vault_signer_seeds(ctx.bumps.vault_authority),
+                        ),
+                        paid,
+                    )?;
+                }
+                ctx.accounts.staking_position.pending_rewards = 0;
+
+                emit!(StakingRewardsClaimed {
+                    user: ctx.accounts.user.key(),
+                    position: ctx.accounts.staking_position.key(),
+                    token_mint: config.token_mint,
+                    reward_mint: config.reward_mint,
+                    amount: paid,
+                    forfeited_amount: pending - paid,
+                    timestamp: current_time,
+                });
+            }
+        }
+        None => require!(
+            ctx.accounts.staking_position.reward_tracked_amount == 0,
+            LaunchpadError::StakingRewardConfigRequired
+        ),
+    }
+
+    let staking_position = &ctx.accounts.staking_position;
+
     // Check if tokens can be unstaked (lock period has passed)
     require!(
-        staking_position.can_unstake(current_time),
+        staking_position.can_unstake(current_time, ctx.accounts.global_config.unstake_grace_seconds),
         LaunchpadError::StakeNotUnlocked
     );
 
@@ -87,7 +172,7 @@ pub fn unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
     let unstake_amount = staking_position.staked_amount;
 
     // Prepare seeds for vault authority PDA signing
-    let signer_seeds: &[&[&[u8]]] = &[&[VAULT_AUTHORITY, &[VAULT_BUMP]]];
+    let signer_seeds = vault_signer_seeds(ctx.bumps.vault_authority);
 
     // Transfer tokens back to user
     let transfer_ctx = CpiContext::new_with_signer(
@@ -113,13 +198,15 @@ pub fn unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
         remaining_staked: 0, // Always 0 since we unstake everything
         duration_staked,
         unstake_time: current_time,
+        epoch_index: ctx.accounts.reward_epoch.as_ref().map_or(0, |epoch| epoch.epoch_index),
     });
 
     msg!(
-        "User {} unstaked {} tokens from mint {}",
+        "User {} unstaked {} tokens from mint {} (position {})",
         ctx.accounts.user.key(),
         unstake_amount,
-        ctx.accounts.token_mint.key()
+        ctx.accounts.token_mint.key(),
+        position_index
     );
 
     // Position account is automatically closed by the 'close = user' constraint
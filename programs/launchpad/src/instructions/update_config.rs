@@ -2,6 +2,7 @@ use anchor_lang::prelude::*;
 
 use crate::constants::*;
 use crate::errors::LaunchpadError;
+use crate::events::{ConfigChangeStaged, ConfigUpdated};
 use crate::state::GlobalConfig;
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -15,6 +16,23 @@ pub struct UpdateConfigParams {
     pub paused: Option<bool>,
     pub min_stake_duration: Option<i64>,
     pub lb_pair: Option<Pubkey>,
+    pub lock_duration_presets: Option<[i64; MAX_LOCK_DURATION_PRESETS]>,
+    pub min_liquidity_ratio_bps: Option<u16>,
+    pub max_dividend_records_per_user: Option<u32>,
+    pub refund_delay: Option<i64>,
+    pub creator_bond: Option<u64>,
+    pub min_points_per_contribution: Option<u64>,
+    pub max_lifetime_contribution_sol: Option<u64>,
+    pub enforce_unique_symbols: Option<bool>,
+    pub config_timelock: Option<i64>,
+    pub unstake_grace_seconds: Option<i64>,
+    pub min_stake_tokens: Option<u64>,
+    pub swap_fee_account: Option<Pubkey>,
+    pub swap_fee_bps: Option<u16>,
+    pub fee_creator_share_bps: Option<u16>,
+    pub min_pool_fee_bps: Option<u16>,
+    pub max_pool_fee_bps: Option<u16>,
+    pub max_start_delay: Option<i64>,
 }
 
 #[derive(Accounts)]
@@ -36,10 +54,20 @@ pub fn update_config(
     params: UpdateConfigParams,
 ) -> Result<()> {
     let config = &mut ctx.accounts.global_config;
+    let before = GlobalConfig::clone(config);
+
+    // Sensitive fields are staged for apply_config while a timelock is configured
+    let timelocked = config.config_timelock > 0;
+    let mut staged = false;
 
     // Update configuration parameters
     if let Some(points_signer) = params.points_signer {
-        config.points_signer = points_signer;
+        if timelocked {
+            config.pending_points_signer = Some(points_signer);
+            staged = true;
+        } else {
+            config.points_signer = points_signer;
+        }
     }
 
     if let Some(points_per_sol) = params.points_per_sol {
@@ -62,6 +90,8 @@ pub fn update_config(
         config.max_duration = max_duration;
     }
 
+    config.validate_launch_bounds()?;
+
     if let Some(paused) = params.paused {
         config.paused = paused;
     }
@@ -71,9 +101,168 @@ pub fn update_config(
     }
 
     if let Some(lb_pair) = params.lb_pair {
-        config.lb_pair = lb_pair;
+        if timelocked {
+            config.pending_lb_pair = Some(lb_pair);
+            staged = true;
+        } else {
+            config.lb_pair = lb_pair;
+        }
+    }
+
+    if let Some(lock_duration_presets) = params.lock_duration_presets {
+        require!(
+            lock_duration_presets.iter().all(|preset| *preset >= 0),
+            LaunchpadError::InvalidStakeDuration
+        );
+        config.lock_duration_presets = lock_duration_presets;
+    }
+
+    if let Some(min_liquidity_ratio_bps) = params.min_liquidity_ratio_bps {
+        require!(
+            min_liquidity_ratio_bps as u64 <= MAX_BASIS_POINT,
+            LaunchpadError::InvalidAmount
+        );
+        config.min_liquidity_ratio_bps = min_liquidity_ratio_bps;
+    }
+
+    if let Some(max_dividend_records_per_user) = params.max_dividend_records_per_user {
+        config.max_dividend_records_per_user = max_dividend_records_per_user;
+    }
+
+    if let Some(refund_delay) = params.refund_delay {
+        require!(refund_delay >= 0, LaunchpadError::InvalidDuration);
+        config.refund_delay = refund_delay;
+    }
+
+    if let Some(creator_bond) = params.creator_bond {
+        config.creator_bond = creator_bond;
+    }
+
+    if let Some(min_points_per_contribution) = params.min_points_per_contribution {
+        config.min_points_per_contribution = min_points_per_contribution;
+    }
+
+    if let Some(max_lifetime_contribution_sol) = params.max_lifetime_contribution_sol {
+        config.max_lifetime_contribution_sol = max_lifetime_contribution_sol;
+    }
+
+    if let Some(enforce_unique_symbols) = params.enforce_unique_symbols {
+        config.enforce_unique_symbols = enforce_unique_symbols;
+    }
+
+    if let Some(min_stake_tokens) = params.min_stake_tokens {
+        config.min_stake_tokens = min_stake_tokens;
+    }
+
+    if let Some(swap_fee_bps) = params.swap_fee_bps {
+        require!(swap_fee_bps <= MAX_SWAP_FEE_BPS, LaunchpadError::InvalidAmount);
+        config.swap_fee_bps = swap_fee_bps;
+    }
+
+    if let Some(fee_creator_share_bps) = params.fee_creator_share_bps {
+        require!(
+            fee_creator_share_bps as u64 <= MAX_BASIS_POINT,
+            LaunchpadError::InvalidAmount
+        );
+        config.fee_creator_share_bps = fee_creator_share_bps;
+    }
+
+    if let Some(min_pool_fee_bps) = params.min_pool_fee_bps {
+        config.min_pool_fee_bps = min_pool_fee_bps;
+    }
+
+    if let Some(max_pool_fee_bps) = params.max_pool_fee_bps {
+        config.max_pool_fee_bps = max_pool_fee_bps;
+    }
+
+    if params.min_pool_fee_bps.is_some() || params.max_pool_fee_bps.is_some() {
+        require!(
+            config.min_pool_fee_bps <= config.max_pool_fee_bps
+                && config.max_pool_fee_bps as u64 <= MAX_BASIS_POINT,
+            LaunchpadError::InvalidAmount
+        );
+    }
+
+    if let Some(max_start_delay) = params.max_start_delay {
+        require!(max_start_delay >= 0, LaunchpadError::InvalidDuration);
+        config.max_start_delay = max_start_delay;
     }
 
+    // Swaps validate against this field, so the rotation takes effect in one write
+    if let Some(swap_fee_account) = params.swap_fee_account {
+        config.swap_fee_account = swap_fee_account;
+        msg!("Swap fee account set to {}", swap_fee_account);
+    }
+
+    if let Some(unstake_grace_seconds) = params.unstake_grace_seconds {
+        require!(
+            (0..=MAX_UNSTAKE_GRACE_SECONDS).contains(&unstake_grace_seconds),
+            LaunchpadError::InvalidDuration
+        );
+        config.unstake_grace_seconds = unstake_grace_seconds;
+    }
+
+    if let Some(config_timelock) = params.config_timelock {
+        require!(
+            (0..=MAX_CONFIG_TIMELOCK).contains(&config_timelock),
+            LaunchpadError::InvalidDuration
+        );
+        // Lengthening applies immediately, shortening waits out the current timelock
+        if timelocked && config_timelock < config.config_timelock {
+            config.pending_config_timelock = Some(config_timelock);
+            staged = true;
+        } else {
+            config.config_timelock = config_timelock;
+        }
+    }
+
+    if staged {
+        let clock = Clock::get()?;
+        config.pending_effective_at = clock.unix_timestamp
+            .checked_add(config.config_timelock)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
+        emit!(ConfigChangeStaged {
+            admin: ctx.accounts.admin.key(),
+            pending_points_signer: config.pending_points_signer,
+            pending_lb_pair: config.pending_lb_pair,
+            pending_config_timelock: config.pending_config_timelock,
+            effective_at: config.pending_effective_at,
+            timestamp: clock.unix_timestamp,
+        });
+
+        msg!("Sensitive config changes staged until {}", config.pending_effective_at);
+    }
+
+    // Report only the fields whose value actually changed, staged fields are reported by ConfigChangeStaged
+    macro_rules! changed_fields {
+        ($source:expr, [$($field:ident),* $(,)?]) => {
+            UpdateConfigParams {
+                $($field: (config.$field != before.$field).then_some($source.$field),)*
+            }
+        };
+    }
+    macro_rules! diff {
+        ($source:expr) => {
+            changed_fields!($source, [
+                points_signer, points_per_sol, min_target_sol, max_target_sol, min_duration,
+                max_duration, paused, min_stake_duration, lb_pair, lock_duration_presets,
+                min_liquidity_ratio_bps, max_dividend_records_per_user, refund_delay, creator_bond,
+                min_points_per_contribution, max_lifetime_contribution_sol, enforce_unique_symbols,
+                config_timelock, unstake_grace_seconds, min_stake_tokens, swap_fee_account,
+                swap_fee_bps, fee_creator_share_bps, min_pool_fee_bps, max_pool_fee_bps,
+                max_start_delay,
+            ])
+        };
+    }
+
+    emit!(ConfigUpdated {
+        admin: ctx.accounts.admin.key(),
+        previous: diff!(before),
+        updated: diff!(config),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
     msg!("Global config updated successfully");
 
     Ok(())
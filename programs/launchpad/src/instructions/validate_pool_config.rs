@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{LAUNCH_POOL_SEED, VAULT_AUTHORITY};
+use crate::cp_amm;
+use crate::errors::LaunchpadError;
+use crate::events::PoolConfigValidated;
+use crate::state::LaunchPool;
+
+/// cp_amm config_type for configs usable with initialize_pool_with_dynamic_config
+const DYNAMIC_CONFIG_TYPE: u8 = 1;
+
+#[derive(Accounts)]
+pub struct ValidatePoolConfig<'info> {
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// CHECK: pool config (cp_amm Config), deserialized and checked in the handler
+    #[account(owner = cp_amm::ID @ LaunchpadError::InvalidPoolConfig)]
+    pub pool_config: UncheckedAccount<'info>,
+}
+
+/// Deserialize a cp_amm Config and check it can be used to migrate launches
+/// The config must be a dynamic config whose pool creator authority is the vault authority
+pub fn check_pool_config(
+    pool_config: &AccountInfo,
+    vault_authority: &Pubkey,
+) -> Result<cp_amm::accounts::Config> {
+    require_keys_eq!(*pool_config.owner, cp_amm::ID, LaunchpadError::InvalidPoolConfig);
+
+    let data = pool_config.try_borrow_data()?;
+    let config = cp_amm::accounts::Config::try_deserialize(&mut &data[..])
+        .map_err(|_| LaunchpadError::InvalidPoolConfig)?;
+
+    require!(
+        config.config_type == DYNAMIC_CONFIG_TYPE,
+        LaunchpadError::InvalidPoolConfig
+    );
+    require_keys_eq!(
+        config.pool_creator_authority,
+        *vault_authority,
+        LaunchpadError::InvalidPoolConfig
+    );
+
+    Ok(config)
+}
+
+/// Check the Meteora pool config a launch would migrate against and emit its parameters
+pub fn validate_pool_config(ctx: Context<ValidatePoolConfig>) -> Result<()> {
+    let config = check_pool_config(
+        &ctx.accounts.pool_config,
+        &ctx.accounts.vault_authority.key(),
+    )?;
+
+    emit!(PoolConfigValidated {
+        launch_pool: ctx.accounts.launch_pool.key(),
+        pool_config: ctx.accounts.pool_config.key(),
+        pool_creator_authority: config.pool_creator_authority,
+        config_type: config.config_type,
+        activation_type: config.activation_type,
+        collect_fee_mode: config.collect_fee_mode,
+        cliff_fee_numerator: config.pool_fees.base_fee.cliff_fee_numerator,
+        sqrt_min_price: config.sqrt_min_price,
+        sqrt_max_price: config.sqrt_max_price,
+    });
+
+    msg!("Pool config {} is valid for migration", ctx.accounts.pool_config.key());
+
+    Ok(())
+}
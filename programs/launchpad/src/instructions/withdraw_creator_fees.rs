@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+
+use crate::const_pda::const_authority::vault_signer_seeds;
+use crate::constants::{CREATOR_FEE_ESCROW_SEED, LAUNCH_POOL_SEED, VAULT_AUTHORITY};
+use crate::errors::LaunchpadError;
+use crate::events::CreatorFeesWithdrawn;
+use crate::state::LaunchPool;
+
+#[derive(Accounts)]
+pub struct WithdrawCreatorFees<'info> {
+    /// Creator fee recipient (the creator unless an override is set)
+    #[account(
+        constraint = recipient.key() == launch_pool.creator_fee_recipient() @ LaunchpadError::NotCreator
+    )]
+    pub recipient: Signer<'info>,
+
+    /// Launch pool account
+    #[account(
+        seeds = [LAUNCH_POOL_SEED, launch_pool.creator.as_ref(), &launch_pool.index.to_le_bytes()],
+        bump = launch_pool.bump,
+    )]
+    pub launch_pool: Box<Account<'info, LaunchPool>>,
+
+    /// Mint of the escrowed fees (launch token or quote mint)
+    pub token_mint: Account<'info, Mint>,
+
+    /// Vault authority PDA
+    /// CHECK: vault authority
+    #[account(
+        seeds = [VAULT_AUTHORITY],
+        bump,
+    )]
+    pub vault_authority: SystemAccount<'info>,
+
+    /// Creator fee escrow filled by claim_pool_fee
+    #[account(
+        mut,
+        seeds = [CREATOR_FEE_ESCROW_SEED, launch_pool.key().as_ref(), token_mint.key().as_ref()],
+        bump,
+        token::mint = token_mint,
+        token::authority = vault_authority,
+    )]
+    pub creator_fee_escrow: Box<Account<'info, TokenAccount>>,
+
+    /// Recipient's token account
+    #[account(
+        mut,
+        token::mint = token_mint,
+        token::authority = recipient,
+    )]
+    pub recipient_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+}
+
+/// Withdraw the creator's escrowed pool fees for one mint
+pub fn withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>) -> Result<()> {
+    let amount = ctx.accounts.creator_fee_escrow.amount;
+    require!(amount > 0, LaunchpadError::NothingToClaim);
+
+    let signer_seeds = vault_signer_seeds(ctx.bumps.vault_authority);
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.creator_fee_escrow.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.vault_authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+
+    let clock = Clock::get()?;
+    emit!(CreatorFeesWithdrawn {
+        pool: ctx.accounts.launch_pool.key(),
+        recipient: ctx.accounts.recipient.key(),
+        token_mint: ctx.accounts.token_mint.key(),
+        amount,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Withdrew {} escrowed creator fees of mint {}", amount, ctx.accounts.token_mint.key());
+
+    Ok(())
+}
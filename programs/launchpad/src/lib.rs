@@ -28,6 +28,16 @@ pub mod launchpad {
         instructions::initialize_config(ctx, params)
     }
 
+    /// Grow a global config created under an older layout to the current one (admin only)
+    pub fn migrate_config(ctx: Context<MigrateConfig>) -> Result<()> {
+        instructions::migrate_config(ctx)
+    }
+
+    /// Grow a launch pool created under an older layout to the current one (permissionless)
+    pub fn migrate_launch_pool(ctx: Context<MigrateLaunchPool>) -> Result<()> {
+        instructions::migrate_launch_pool(ctx)
+    }
+
     /// Initialize a new token launch pool
     pub fn initialize_launch(
         ctx: Context<InitializeLaunch>,
@@ -36,26 +46,169 @@ pub mod launchpad {
         instructions::initialize_launch(ctx, params)
     }
 
+    /// Create metadata for a launch initialized without it and revoke its mint authority (creator only)
+    pub fn create_launch_metadata(
+        ctx: Context<CreateLaunchMetadata>,
+        params: CreateLaunchMetadataParams,
+    ) -> Result<()> {
+        instructions::create_launch_metadata(ctx, params)
+    }
+
     /// Participate in the launch using points
     pub fn participate_with_points(
         ctx: Context<ParticipateWithPoints>,
         points_to_use: u64,
         total_points: u64,
+        nonce: u64,
+        signature: [u8; 64],
+        idempotency_key: Option<u64>,
+    ) -> Result<()> {
+        instructions::participate_with_points(
+            ctx,
+            points_to_use,
+            total_points,
+            nonce,
+            signature,
+            idempotency_key,
+            Vec::new(),
+        )
+    }
+
+    /// Participate in a whitelisted launch using points and a Merkle proof of the user's wallet
+    pub fn participate_with_proof(
+        ctx: Context<ParticipateWithPoints>,
+        points_to_use: u64,
+        total_points: u64,
+        nonce: u64,
         signature: [u8; 64],
+        idempotency_key: Option<u64>,
+        whitelist_proof: Vec<[u8; 32]>,
     ) -> Result<()> {
         instructions::participate_with_points(
             ctx,
             points_to_use,
             total_points,
+            nonce,
+            signature,
+            idempotency_key,
+            whitelist_proof,
+        )
+    }
+
+    /// Contribute SOL directly to a launch that allows open participation, without points
+    pub fn participate_with_sol(ctx: Context<ParticipateWithSol>, sol_amount: u64) -> Result<()> {
+        instructions::participate_with_sol(ctx, sol_amount)
+    }
+
+    /// Split one signed points allowance across several active launches atomically
+    pub fn participate_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ParticipateBatch<'info>>,
+        points_to_use: Vec<u64>,
+        total_points: u64,
+        nonce: u64,
+        signature: [u8; 64],
+    ) -> Result<()> {
+        instructions::participate_batch(ctx, points_to_use, total_points, nonce, signature)
+    }
+
+    /// Claim excess SOL from a migrated pool and contribute it to an active pool
+    pub fn reinvest_excess(
+        ctx: Context<ReinvestExcess>,
+        points_to_use: u64,
+        total_points: u64,
+        nonce: u64,
+        signature: [u8; 64],
+    ) -> Result<()> {
+        instructions::reinvest_excess(
+            ctx,
+            points_to_use,
+            total_points,
+            nonce,
             signature,
         )
     }
 
-    /// Finalize the launch (success or failure)
+    /// Reset a user's consumed points to the value signed by points_signer
+    pub fn reconcile_user_points(
+        ctx: Context<ReconcileUserPoints>,
+        points_consumed: u64,
+        signature: [u8; 64],
+    ) -> Result<()> {
+        instructions::reconcile_user_points(ctx, points_consumed, signature)
+    }
+
+    /// Pause or resume contributions to a single pool (admin only)
+    pub fn set_pool_paused(ctx: Context<SetPoolPaused>, paused: bool) -> Result<()> {
+        instructions::set_pool_paused(ctx, paused)
+    }
+
+    /// Open a scheduled launch for contributions once its start time is reached (permissionless)
+    pub fn activate_launch(ctx: Context<ActivateLaunch>) -> Result<()> {
+        instructions::activate_launch(ctx)
+    }
+
+    /// Cancel a launch before it starts, burning its supply and refunding the bond (creator only)
+    pub fn cancel_launch(ctx: Context<CancelLaunch>) -> Result<()> {
+        instructions::cancel_launch(ctx)
+    }
+
+    /// Finalize the launch (success or failure), creator or admin only
     pub fn finalize_launch(ctx: Context<FinalizeLaunch>) -> Result<()> {
         instructions::finalize_launch(ctx)
     }
 
+    /// Finalize a batch of launch pools passed as remaining accounts, skipping ineligible ones (admin only)
+    pub fn finalize_launch_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeLaunchBatch<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_launch_batch(ctx)
+    }
+
+    /// Write the immutable financial summary of a failed or migrated launch
+    pub fn finalize_summary(ctx: Context<FinalizeSummary>) -> Result<()> {
+        instructions::finalize_summary(ctx)
+    }
+
+    /// Emit a snapshot of the pool's fundraising progress for indexers
+    pub fn emit_pool_snapshot(ctx: Context<EmitPoolSnapshot>) -> Result<()> {
+        instructions::emit_pool_snapshot(ctx)
+    }
+
+    /// Emit the launch's implied token price for display
+    pub fn get_implied_price(ctx: Context<GetImpliedPrice>) -> Result<()> {
+        instructions::get_implied_price(ctx)
+    }
+
+    /// Return the creator's vesting status, meant to be simulated
+    pub fn get_creator_vesting(ctx: Context<GetCreatorVesting>) -> Result<CreatorVestingView> {
+        instructions::get_creator_vesting(ctx)
+    }
+
+    /// Return what a user would receive from claim_user_rewards, meant to be simulated
+    pub fn preview_user_rewards(ctx: Context<PreviewUserRewards>) -> Result<UserRewardsPreview> {
+        instructions::preview_user_rewards(ctx)
+    }
+
+    /// Return platform-wide launch totals, meant to be simulated
+    pub fn get_platform_stats(ctx: Context<GetPlatformStats>) -> Result<PlatformStatsView> {
+        instructions::get_platform_stats(ctx)
+    }
+
+    /// Emit the SOL and sale tokens remaining before the launch target
+    pub fn get_remaining_capacity(ctx: Context<GetRemainingCapacity>) -> Result<()> {
+        instructions::get_remaining_capacity(ctx)
+    }
+
+    /// Emit whether a wallet's merkle proof passes the launch whitelist
+    pub fn check_whitelist(ctx: Context<CheckWhitelist>, user: Pubkey, proof: Vec<[u8; 32]>) -> Result<()> {
+        instructions::check_whitelist(ctx, user, proof)
+    }
+
+    /// Inspect and optionally repair a pool's liquidity/excess SOL split (admin only)
+    pub fn recompute_raise_split(ctx: Context<RecomputeRaiseSplit>, apply: bool) -> Result<()> {
+        instructions::recompute_raise_split(ctx, apply)
+    }
+
     /// Update global configuration (admin only)
     pub fn update_config(
         ctx: Context<UpdateConfig>,
@@ -64,9 +217,54 @@ pub mod launchpad {
         instructions::update_config(ctx, params)
     }
 
+    /// Apply staged sensitive config changes after the timelock (admin only)
+    pub fn apply_config(ctx: Context<ApplyConfig>) -> Result<()> {
+        instructions::apply_config(ctx)
+    }
+
+    /// Propose a new admin, effective once accepted (admin only)
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        instructions::propose_admin(ctx, new_admin)
+    }
+
+    /// Accept a pending admin proposal (pending admin only)
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        instructions::accept_admin(ctx)
+    }
+
+    /// Recover funds from a vault-authority token account while the platform is paused (admin only)
+    pub fn emergency_withdraw(ctx: Context<EmergencyWithdraw>, amount: u64) -> Result<()> {
+        instructions::emergency_withdraw(ctx, amount)
+    }
+
+    /// Close the global config so it can be reinitialized; all launches must be settled (admin only)
+    pub fn close_config(ctx: Context<CloseConfig>, confirm: bool) -> Result<()> {
+        instructions::close_config(ctx, confirm)
+    }
+
+    /// Release the token symbol held by a failed launch (permissionless)
+    pub fn release_symbol(ctx: Context<ReleaseSymbol>) -> Result<()> {
+        instructions::release_symbol(ctx)
+    }
+
+    /// Stop counting a migrated or fully refunded launch pool against the global config (permissionless)
+    pub fn settle_launch_pool(ctx: Context<SettleLaunchPool>) -> Result<()> {
+        instructions::settle_launch_pool(ctx)
+    }
+
     /// Create Meteora liquidity pool after successful launch
     pub fn create_meteora_pool(ctx: Context<DammV2>) -> Result<()> {
-        ctx.accounts.create_pool()
+        ctx.accounts.create_pool(ctx.bumps.vault_authority, ctx.bumps.platform_stats)
+    }
+
+    /// Finalize, migrate and optionally lock extra liquidity for a successful launch in one instruction
+    pub fn complete_launch(ctx: Context<CompleteLaunch>, extra_lock_amount: u128) -> Result<()> {
+        instructions::complete_launch(ctx, extra_lock_amount)
+    }
+
+    /// Check the Meteora pool config a launch would migrate against
+    pub fn validate_pool_config(ctx: Context<ValidatePoolConfig>) -> Result<()> {
+        instructions::validate_pool_config(ctx)
     }
 
     /// Lock liquidity in Meteora pool (admin only)
@@ -79,20 +277,69 @@ pub mod launchpad {
         instructions::claim_user_rewards(ctx)
     }
 
+    /// Close a fully claimed user position and return its rent to the user
+    pub fn close_user_position(ctx: Context<CloseUserPosition>) -> Result<()> {
+        instructions::close_user_position(ctx)
+    }
+
     /// Claim creator tokens (with vesting)
     pub fn claim_creator_tokens(ctx: Context<ClaimCreatorTokens>) -> Result<()> {
         instructions::claim_creator_tokens(ctx)
     }
 
+    /// Claim vested creator tokens from several migrated pools passed as remaining accounts
+    pub fn claim_creator_tokens_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimCreatorTokensBatch<'info>>,
+    ) -> Result<()> {
+        instructions::claim_creator_tokens_batch(ctx)
+    }
+
+    /// Deposit dividend tokens into the dividend vault with on-chain accounting
+    pub fn deposit_dividends(ctx: Context<DepositDividends>, amount: u64) -> Result<()> {
+        instructions::deposit_dividends(ctx, amount)
+    }
+
     /// Claim token dividends with points_signer verification
     pub fn claim_token_dividends(
         ctx: Context<ClaimTokenDividends>,
         total_dividend_amount: u64,
+        expiry_ts: i64,
         signature: [u8; 64],
     ) -> Result<()> {
         instructions::claim_token_dividends(
             ctx,
             total_dividend_amount,
+            expiry_ts,
+            signature,
+        )
+    }
+
+    /// Convert a migrated pool's undistributed excess SOL into a SOL dividend campaign (admin only)
+    pub fn convert_excess_to_dividends(ctx: Context<ConvertExcessToDividends>) -> Result<()> {
+        instructions::convert_excess_to_dividends(ctx)
+    }
+
+    /// Sweep excess SOL rounding dust to treasury once all participants have claimed (admin only)
+    pub fn sweep_excess_dust(ctx: Context<SweepExcessDust>) -> Result<()> {
+        instructions::sweep_excess_dust(ctx)
+    }
+
+    /// Sweep sale tokens still unclaimed after the grace period to treasury (admin only)
+    pub fn sweep_unsold_tokens(ctx: Context<SweepUnsoldTokens>) -> Result<()> {
+        instructions::sweep_unsold_tokens(ctx)
+    }
+
+    /// Claim SOL dividends with points_signer verification
+    pub fn claim_sol_dividends(
+        ctx: Context<ClaimSolDividends>,
+        total_dividend_amount: u64,
+        expiry_ts: i64,
+        signature: [u8; 64],
+    ) -> Result<()> {
+        instructions::claim_sol_dividends(
+            ctx,
+            total_dividend_amount,
+            expiry_ts,
             signature,
         )
     }
@@ -105,20 +352,72 @@ pub mod launchpad {
         instructions::stake_tokens(ctx, params)
     }
 
-    /// Unstake all tokens and close position
-    pub fn unstake_tokens(ctx: Context<UnstakeTokens>) -> Result<()> {
-        instructions::unstake_tokens(ctx)
+    /// Unstake all tokens and close the position at `position_index`
+    pub fn unstake_tokens(ctx: Context<UnstakeTokens>, position_index: u64) -> Result<()> {
+        instructions::unstake_tokens(ctx, position_index)
+    }
+
+    /// Move a staking position to another wallet (both wallets sign)
+    pub fn transfer_stake_position(ctx: Context<TransferStakePosition>, position_index: u64) -> Result<()> {
+        instructions::transfer_stake_position(ctx, position_index)
+    }
+
+    /// Move a position staked before positions were indexed to an indexed PDA
+    pub fn migrate_stake_position(ctx: Context<MigrateStakePosition>) -> Result<()> {
+        instructions::migrate_stake_position(ctx)
+    }
+
+    /// Extend the lock of an existing staking position
+    pub fn extend_lock(
+        ctx: Context<ExtendLock>,
+        position_index: u64,
+        additional_duration: i64,
+    ) -> Result<()> {
+        instructions::extend_lock(ctx, position_index, additional_duration)
+    }
+
+    /// Create or re-rate the treasury-funded staking reward stream of a token (admin only)
+    pub fn configure_staking_rewards(ctx: Context<ConfigureStakingRewards>, reward_rate: u64) -> Result<()> {
+        instructions::configure_staking_rewards(ctx, reward_rate)
+    }
+
+    /// Fund the staking reward stream of a token, accrual never exceeds the funded amount
+    pub fn fund_staking_rewards(ctx: Context<FundStakingRewards>, amount: u64) -> Result<()> {
+        instructions::fund_staking_rewards(ctx, amount)
+    }
+
+    /// Claim the staking rewards accrued by the position at `position_index`
+    pub fn claim_staking_rewards(ctx: Context<ClaimStakingRewards>, position_index: u64) -> Result<()> {
+        instructions::claim_staking_rewards(ctx, position_index)
+    }
+
+    /// Open a reward epoch snapshotting total staked for a token (admin only)
+    pub fn open_reward_epoch(ctx: Context<OpenRewardEpoch>) -> Result<()> {
+        instructions::open_reward_epoch(ctx)
+    }
+
+    /// Override or reset the recipient of a launch's creator fee share (admin only)
+    pub fn set_creator_fee_recipient(
+        ctx: Context<SetCreatorFeeRecipient>,
+        recipient: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_creator_fee_recipient(ctx, recipient)
     }
 
     pub fn claim_pool_fee(
         ctx: Context<ClaimPositionFee>,
     ) -> Result<()> {
         ctx.accounts
-            .claim_position_fee()?;
+            .claim_position_fee(ctx.bumps.vault_authority)?;
 
         Ok(())
     }
 
+    /// Withdraw escrowed creator pool fees for one mint (creator fee recipient only)
+    pub fn withdraw_creator_fees(ctx: Context<WithdrawCreatorFees>) -> Result<()> {
+        instructions::withdraw_creator_fees(ctx)
+    }
+
     /// Swap tokens with optional fee
     pub fn swap<'a, 'b, 'c, 'info>(
         ctx: Context<'a, 'b, 'c, 'info, DlmmSwap<'info>>,
@@ -128,4 +427,14 @@ pub mod launchpad {
     ) -> Result<()> {
         instructions::handle_dlmm_swap(ctx, amount_in, min_amount_out, remaining_accounts_info)
     }
+
+    /// Sell project tokens for WSOL, with the fee taken from the WSOL output
+    pub fn swap_sell<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DlmmSwap<'info>>,
+        amount_in: u64,
+        min_amount_out: u64,
+        remaining_accounts_info: dlmm::types::RemainingAccountsInfo
+    ) -> Result<()> {
+        instructions::handle_dlmm_swap_sell(ctx, amount_in, min_amount_out, remaining_accounts_info)
+    }
 }
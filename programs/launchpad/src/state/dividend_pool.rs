@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::errors::LaunchpadError;
+
+/// Running totals of token dividends paid out for a mint, used to reconcile the dividend vault
+#[account]
+pub struct DividendPool {
+    /// Token mint the dividends are paid in
+    pub token_mint: Pubkey,
+
+    /// Total dividend tokens transferred to users
+    pub total_distributed: u64,
+
+    /// Number of successful claims
+    pub claim_count: u64,
+
+    /// Total dividend tokens deposited through deposit_dividends
+    pub total_deposited: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Reserved space for future upgrades
+    pub reserved: [u64; 3],
+}
+
+impl DividendPool {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // token_mint
+        8 +  // total_distributed
+        8 +  // claim_count
+        8 +  // total_deposited
+        1 +  // bump
+        8 * 3; // reserved
+
+    pub const SEED: &'static [u8] = b"dividend_pool";
+
+    /// Add a deposit to the running totals
+    pub fn record_deposit(&mut self, amount: u64) -> Result<()> {
+        self.total_deposited = self.total_deposited
+            .checked_add(amount)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Deposited dividends not yet claimed, the most any further claims can pay out
+    pub fn remaining_deposit(&self) -> u64 {
+        self.total_deposited.saturating_sub(self.total_distributed)
+    }
+
+    /// Add a claim to the running totals
+    pub fn record_claim(&mut self, claimed_amount: u64) -> Result<()> {
+        self.total_distributed = self.total_distributed
+            .checked_add(claimed_amount)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        self.claim_count = self.claim_count
+            .checked_add(1)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool() -> DividendPool {
+        DividendPool {
+            token_mint: Pubkey::new_unique(),
+            total_distributed: 0,
+            claim_count: 0,
+            total_deposited: 0,
+            bump: 255,
+            reserved: [0; 3],
+        }
+    }
+
+    #[test]
+    fn deposits_accumulate() {
+        let mut pool = pool();
+        pool.record_deposit(1_000).unwrap();
+        pool.record_deposit(250).unwrap();
+
+        assert_eq!(pool.total_deposited, 1_250);
+        assert_eq!(pool.remaining_deposit(), 1_250);
+
+        pool.total_deposited = u64::MAX;
+        assert!(pool.record_deposit(1).is_err());
+    }
+
+    #[test]
+    fn claims_are_capped_at_deposits() {
+        let mut pool = pool();
+        pool.record_deposit(1_000).unwrap();
+
+        pool.record_claim(600).unwrap();
+        // Signed totals summing to 1_001 would leave a claim of 401 against 400 remaining
+        assert_eq!(pool.remaining_deposit(), 400);
+        assert!(401 > pool.remaining_deposit());
+
+        pool.record_claim(400).unwrap();
+        assert_eq!(pool.remaining_deposit(), 0);
+        assert_eq!(pool.claim_count, 2);
+    }
+}
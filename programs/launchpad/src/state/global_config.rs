@@ -1,5 +1,10 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    DEFAULT_FEE_CREATOR_SHARE_BPS, DEFAULT_MAX_DIVIDEND_RECORDS_PER_USER, DEFAULT_MAX_POOL_FEE_BPS, DEFAULT_MAX_START_DELAY,
+    DEFAULT_MIN_LIQUIDITY_RATIO_BPS, DEFAULT_MIN_POOL_FEE_BPS, DEFAULT_SWAP_FEE_BPS, MAX_LOCK_DURATION_PRESETS,
+};
+
 #[account]
 pub struct GlobalConfig {
     /// Admin address (can update configuration)
@@ -38,8 +43,75 @@ pub struct GlobalConfig {
     /// bump seed
     pub bump: u8,
 
-    /// Reserved space
-    pub reserved: [u64; 9],
+    /// Allowed staking lock durations (seconds), 0 = unused slot
+    /// When all slots are 0, any lock_duration >= min_stake_duration is accepted
+    pub lock_duration_presets: [i64; MAX_LOCK_DURATION_PRESETS],
+
+    /// Minimum liquidity_sol as a share of raised_sol at migration (basis points)
+    pub min_liquidity_ratio_bps: u16,
+
+    /// Maximum number of dividend records (token and SOL) a single user can open
+    pub max_dividend_records_per_user: u32,
+
+    /// Delay after a failed launch is finalized before refunds can be claimed (seconds)
+    pub refund_delay: i64,
+
+    /// SOL bond (lamports) a creator deposits at initialize_launch, 0 = disabled
+    pub creator_bond: u64,
+
+    /// Minimum points a single contribution must use, 0 = disabled
+    pub min_points_per_contribution: u64,
+
+    /// Maximum SOL a user may contribute across all launches, 0 = unlimited
+    pub max_lifetime_contribution_sol: u64,
+
+    /// Whether each token symbol may only be used by one launch
+    pub enforce_unique_symbols: bool,
+
+    /// Minimum stake in whole tokens, scaled by the mint's decimals (0 = no minimum)
+    pub min_stake_tokens: u64,
+
+    /// Seconds an unstake may precede unlock_time to absorb validator clock skew, 0 = exact
+    pub unstake_grace_seconds: i64,
+
+    /// Delay before sensitive config changes take effect (seconds), 0 = apply immediately
+    pub config_timelock: i64,
+
+    /// Staged points_signer change
+    pub pending_points_signer: Option<Pubkey>,
+
+    /// Staged lb_pair change
+    pub pending_lb_pair: Option<Pubkey>,
+
+    /// Staged config_timelock reduction
+    pub pending_config_timelock: Option<i64>,
+
+    /// Time the staged changes can be applied, 0 = nothing staged
+    pub pending_effective_at: i64,
+
+    /// WSOL account receiving swap fees, default = any admin-owned WSOL account
+    pub swap_fee_account: Pubkey,
+
+    /// Fee charged on DLMM swap input (basis points)
+    pub swap_fee_bps: u16,
+
+    /// Creator share of claimed pool fees (basis points), the rest goes to treasury
+    pub fee_creator_share_bps: u16,
+
+    /// Admin proposed by propose_admin, default = no proposal
+    pub pending_admin: Pubkey,
+
+    /// Minimum Meteora pool base fee a launch may migrate with (basis points)
+    pub min_pool_fee_bps: u16,
+
+    /// Maximum Meteora pool base fee a launch may migrate with (basis points)
+    pub max_pool_fee_bps: u16,
+
+    /// Furthest in the future a launch may set its start_time (seconds, 0 = unlimited)
+    pub max_start_delay: i64,
+
+    /// Launch pools created under this config that settle_launch_pool has not settled yet
+    pub active_pool_count: u64,
 }
 
 impl GlobalConfig {
@@ -56,6 +128,41 @@ impl GlobalConfig {
         8 + // pool_count
         32 + // lb_pair
         1 + // bump
+        8 * MAX_LOCK_DURATION_PRESETS + // lock_duration_presets
+        2 + // min_liquidity_ratio_bps
+        4 + // max_dividend_records_per_user
+        8 + // refund_delay
+        8 + // creator_bond
+        8 + // min_points_per_contribution
+        8 + // max_lifetime_contribution_sol
+        1 + // enforce_unique_symbols
+        8 + // min_stake_tokens
+        8 + // unstake_grace_seconds
+        8 + // config_timelock
+        33 + // pending_points_signer (Option<Pubkey>)
+        33 + // pending_lb_pair (Option<Pubkey>)
+        9 + // pending_config_timelock (Option<i64>)
+        8 + // pending_effective_at
+        32 + // swap_fee_account
+        2 + // swap_fee_bps
+        2 + // fee_creator_share_bps
+        32 + // pending_admin
+        2 + // min_pool_fee_bps
+        2 + // max_pool_fee_bps
+        8 + // max_start_delay
+        8; // active_pool_count (replaces reserved)
+
+    /// Size of configs created before the layout outgrew the original reserved space
+    /// Such accounts must go through migrate_config before any other instruction can load them
+    pub const LEGACY_SIZE: usize = 8 + // discriminator
+        32 + // admin
+        32 + // points_signer
+        8 * 5 + // points_per_sol, min/max_target_sol, min/max_duration
+        1 + // paused
+        8 + // min_stake_duration
+        8 + // pool_count
+        32 + // lb_pair
+        1 + // bump
         8 * 9; // reserved
 
     pub const SEED: &'static [u8] = b"global_config";
@@ -73,10 +180,102 @@ impl GlobalConfig {
         self.min_stake_duration = 24 * 60 * 60; // 1 day
         self.pool_count = 0;
         self.lb_pair = lb_pair;
+        self.lock_duration_presets = [0; MAX_LOCK_DURATION_PRESETS];
+        self.min_liquidity_ratio_bps = DEFAULT_MIN_LIQUIDITY_RATIO_BPS;
+        self.max_dividend_records_per_user = DEFAULT_MAX_DIVIDEND_RECORDS_PER_USER;
+        self.refund_delay = 0;
+        self.creator_bond = 0;
+        self.min_points_per_contribution = 0;
+        self.max_lifetime_contribution_sol = 0;
+        self.enforce_unique_symbols = false;
+        self.min_stake_tokens = 0;
+        self.unstake_grace_seconds = 0;
+        self.config_timelock = 0;
+        self.swap_fee_account = Pubkey::default();
+        self.swap_fee_bps = DEFAULT_SWAP_FEE_BPS;
+        self.fee_creator_share_bps = DEFAULT_FEE_CREATOR_SHARE_BPS;
+        self.pending_admin = Pubkey::default();
+        self.min_pool_fee_bps = DEFAULT_MIN_POOL_FEE_BPS;
+        self.max_pool_fee_bps = DEFAULT_MAX_POOL_FEE_BPS;
+        self.max_start_delay = DEFAULT_MAX_START_DELAY;
+        self.active_pool_count = 0;
+        self.clear_pending_changes();
 
         self.bump = bump;
     }
 
+    /// Default the fields added after the legacy layout whose zero value is not a safe default
+    /// Only valid right after migrate_config zero-extends a legacy account
+    pub fn apply_legacy_defaults(&mut self) {
+        self.min_liquidity_ratio_bps = DEFAULT_MIN_LIQUIDITY_RATIO_BPS;
+        self.max_dividend_records_per_user = DEFAULT_MAX_DIVIDEND_RECORDS_PER_USER;
+        self.swap_fee_bps = DEFAULT_SWAP_FEE_BPS;
+        self.fee_creator_share_bps = DEFAULT_FEE_CREATOR_SHARE_BPS;
+        self.min_pool_fee_bps = DEFAULT_MIN_POOL_FEE_BPS;
+        self.max_pool_fee_bps = DEFAULT_MAX_POOL_FEE_BPS;
+        self.max_start_delay = DEFAULT_MAX_START_DELAY;
+        // Legacy pools were never counted, so each one has to go through settle_launch_pool
+        self.active_pool_count = self.pool_count;
+        self.clear_pending_changes();
+    }
+
+    /// Count a newly created launch pool
+    pub fn record_pool_created(&mut self) -> Result<()> {
+        self.pool_count = self.pool_count
+            .checked_add(1)
+            .ok_or(crate::errors::LaunchpadError::MathOverflow)?;
+        self.active_pool_count = self.active_pool_count
+            .checked_add(1)
+            .ok_or(crate::errors::LaunchpadError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Stop counting a launch pool that no longer depends on this config
+    pub fn record_pool_settled(&mut self) -> Result<()> {
+        self.active_pool_count = self.active_pool_count
+            .checked_sub(1)
+            .ok_or(crate::errors::LaunchpadError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Whether a token account may receive swap fees under the current config
+    pub fn is_swap_fee_account(&self, account: &Pubkey, owner: &Pubkey) -> bool {
+        if self.swap_fee_account == Pubkey::default() {
+            *owner == self.admin
+        } else {
+            *account == self.swap_fee_account
+        }
+    }
+
+    /// Whether any sensitive change is staged
+    pub fn has_pending_changes(&self) -> bool {
+        self.pending_points_signer.is_some()
+            || self.pending_lb_pair.is_some()
+            || self.pending_config_timelock.is_some()
+    }
+
+    /// Drop all staged sensitive changes
+    pub fn clear_pending_changes(&mut self) {
+        self.pending_points_signer = None;
+        self.pending_lb_pair = None;
+        self.pending_config_timelock = None;
+        self.pending_effective_at = 0;
+    }
+
+    /// Reject target or duration bounds that no launch could satisfy
+    pub fn validate_launch_bounds(&self) -> Result<()> {
+        require!(
+            self.min_target_sol <= self.max_target_sol
+                && self.min_duration >= 0
+                && self.min_duration <= self.max_duration,
+            crate::errors::LaunchpadError::InvalidConfigBounds
+        );
+
+        Ok(())
+    }
+
     /// Validate fundraising parameters
     pub fn validate_launch_params(&self, target_sol: u64, duration: i64) -> Result<()> {
         require!(
@@ -97,6 +296,88 @@ impl GlobalConfig {
         Ok(())
     }
 
+    /// Validate a launch start time, which may be now but not in the past or beyond max_start_delay
+    pub fn validate_start_time(&self, start_time: i64, current_time: i64) -> Result<()> {
+        require!(
+            start_time >= current_time,
+            crate::errors::LaunchpadError::InvalidStartTime
+        );
+
+        if self.max_start_delay > 0 {
+            require!(
+                start_time <= current_time.saturating_add(self.max_start_delay),
+                crate::errors::LaunchpadError::InvalidStartTime
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Validate a contribution against the minimum points floor
+    pub fn validate_min_points(&self, points_to_use: u64) -> Result<()> {
+        require!(
+            points_to_use >= self.min_points_per_contribution,
+            crate::errors::LaunchpadError::PointsBelowMinimum
+        );
+
+        Ok(())
+    }
+
+    /// Validate that the SOL going into liquidity is deep enough relative to what was raised
+    pub fn validate_liquidity_ratio(&self, liquidity_sol: u64, raised_sol: u64) -> Result<()> {
+        let min_liquidity_sol = (raised_sol as u128)
+            .checked_mul(self.min_liquidity_ratio_bps as u128)
+            .ok_or(crate::errors::LaunchpadError::MathOverflow)?
+            .checked_div(crate::constants::MAX_BASIS_POINT as u128)
+            .ok_or(crate::errors::LaunchpadError::DivisionByZero)?;
+
+        require!(
+            liquidity_sol as u128 >= min_liquidity_sol,
+            crate::errors::LaunchpadError::LiquidityRatioTooLow
+        );
+
+        Ok(())
+    }
+
+    /// Split claimed pool fees into (treasury, creator) amounts that sum to the claimed amount
+    pub fn split_pool_fee(&self, claimed: u64) -> Result<(u64, u64)> {
+        let creator_amount = (claimed as u128)
+            .checked_mul(self.fee_creator_share_bps as u128)
+            .ok_or(crate::errors::LaunchpadError::MathOverflow)?
+            .checked_div(crate::constants::MAX_BASIS_POINT as u128)
+            .ok_or(crate::errors::LaunchpadError::DivisionByZero)? as u64;
+        let treasury_amount = claimed
+            .checked_sub(creator_amount)
+            .ok_or(crate::errors::LaunchpadError::MathOverflow)?;
+
+        Ok((treasury_amount, creator_amount))
+    }
+
+    /// Validate a Meteora pool base fee against the configured bounds
+    pub fn validate_pool_fee_bps(&self, pool_fee_bps: u16) -> Result<()> {
+        require!(
+            (self.min_pool_fee_bps..=self.max_pool_fee_bps).contains(&pool_fee_bps),
+            crate::errors::LaunchpadError::InvalidAmount
+        );
+
+        Ok(())
+    }
+
+    /// Validate a stake amount against the minimum, scaled to the mint's decimals
+    pub fn validate_min_stake(&self, amount: u64, decimals: u8) -> Result<()> {
+        let min_amount = 10u64
+            .checked_pow(decimals as u32)
+            .and_then(|unit| self.min_stake_tokens.checked_mul(unit))
+            .ok_or(crate::errors::LaunchpadError::MathOverflow)?;
+
+        require!(
+            amount >= min_amount,
+            crate::errors::LaunchpadError::StakeBelowMinimum
+        );
+
+        Ok(())
+    }
+
     /// Validate staking parameters
     pub fn validate_stake_params(&self, duration: i64) -> Result<()> {
         require!(
@@ -109,6 +390,163 @@ impl GlobalConfig {
             crate::errors::LaunchpadError::InvalidStakeDuration
         );
 
+        // When presets are configured, the lock must match one of them exactly
+        let presets_enabled = self.lock_duration_presets.iter().any(|preset| *preset != 0);
+        require!(
+            !presets_enabled || self.lock_duration_presets.contains(&duration),
+            crate::errors::LaunchpadError::InvalidStakeDuration
+        );
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    /// Bytes of a config written under the legacy layout, with a recognizable admin and pool_count
+    fn legacy_config_bytes(admin: Pubkey, pool_count: u64) -> Vec<u8> {
+        let mut data = vec![0u8; GlobalConfig::LEGACY_SIZE];
+        data[..8].copy_from_slice(GlobalConfig::DISCRIMINATOR);
+        data[8..40].copy_from_slice(admin.as_ref());
+        // pool_count sits after admin, points_signer, the five u64/i64 bounds, paused and min_stake_duration
+        let pool_count_offset = 8 + 32 + 32 + 8 * 5 + 1 + 8;
+        data[pool_count_offset..pool_count_offset + 8].copy_from_slice(&pool_count.to_le_bytes());
+        data
+    }
+
+    #[test]
+    fn legacy_config_does_not_load_before_migration() {
+        let data = legacy_config_bytes(Pubkey::new_unique(), 7);
+        assert!(GlobalConfig::try_deserialize(&mut &data[..]).is_err());
+    }
+
+    #[test]
+    fn migrated_legacy_config_keeps_fields_and_gets_defaults() {
+        let admin = Pubkey::new_unique();
+        let mut data = legacy_config_bytes(admin, 7);
+        data.resize(GlobalConfig::SIZE, 0);
+
+        let mut config = GlobalConfig::try_deserialize(&mut &data[..]).unwrap();
+        config.apply_legacy_defaults();
+
+        assert_eq!(config.admin, admin);
+        assert_eq!(config.pool_count, 7);
+        assert_eq!(config.active_pool_count, 7);
+        assert_eq!(config.max_dividend_records_per_user, DEFAULT_MAX_DIVIDEND_RECORDS_PER_USER);
+        assert_eq!(config.max_pool_fee_bps, DEFAULT_MAX_POOL_FEE_BPS);
+        assert_eq!(config.max_start_delay, DEFAULT_MAX_START_DELAY);
+        assert!(!config.has_pending_changes());
+
+        let mut written = vec![0u8; GlobalConfig::SIZE];
+        config.try_serialize(&mut &mut written[..]).unwrap();
+        assert!(GlobalConfig::try_deserialize(&mut &written[..]).is_ok());
+    }
+
+    /// Zeroed config with a fresh admin
+    fn empty_config() -> GlobalConfig {
+        let mut data = legacy_config_bytes(Pubkey::new_unique(), 0);
+        data.resize(GlobalConfig::SIZE, 0);
+        GlobalConfig::try_deserialize(&mut &data[..]).unwrap()
+    }
+
+    fn config_with_creator_share(fee_creator_share_bps: u16) -> GlobalConfig {
+        let mut config = empty_config();
+        config.fee_creator_share_bps = fee_creator_share_bps;
+        config
+    }
+
+    #[test]
+    fn pool_fee_split_follows_creator_share() {
+        // (creator share bps, claimed) => (treasury, creator)
+        let cases = [
+            (0, 1_000, (1_000, 0)),
+            (5_000, 1_000, (500, 500)),
+            (7_000, 1_000, (300, 700)),
+            (10_000, 1_000, (0, 1_000)),
+        ];
+
+        for (share_bps, claimed, expected) in cases {
+            assert_eq!(config_with_creator_share(share_bps).split_pool_fee(claimed).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn pool_fee_split_rounds_in_treasury_favor() {
+        for share_bps in [0, 5_000, 7_000, 10_000] {
+            let config = config_with_creator_share(share_bps);
+            for claimed in [0, 1, 3, 999, u64::MAX] {
+                let (treasury, creator) = config.split_pool_fee(claimed).unwrap();
+                assert_eq!(treasury + creator, claimed);
+            }
+        }
+
+        // 70% of 3 is 2.1, the creator gets the floor and the remainder goes to treasury
+        assert_eq!(config_with_creator_share(7_000).split_pool_fee(3).unwrap(), (1, 2));
+    }
+
+    #[test]
+    fn min_stake_scales_with_mint_decimals() {
+        let mut config = empty_config();
+        config.min_stake_tokens = 5;
+
+        // (decimals, smallest accepted amount in base units)
+        for (decimals, min_amount) in [(0, 5), (6, 5_000_000), (9, 5_000_000_000)] {
+            assert!(config.validate_min_stake(min_amount, decimals).is_ok());
+            assert!(config.validate_min_stake(min_amount - 1, decimals).is_err());
+        }
+    }
+
+    fn config_with_bounds(min_target_sol: u64, max_target_sol: u64, min_duration: i64, max_duration: i64) -> GlobalConfig {
+        let mut config = empty_config();
+        config.min_target_sol = min_target_sol;
+        config.max_target_sol = max_target_sol;
+        config.min_duration = min_duration;
+        config.max_duration = max_duration;
+        config
+    }
+
+    #[test]
+    fn launch_bounds_accept_consistent_ranges() {
+        assert!(config_with_bounds(10, 1_000, 3_600, 86_400).validate_launch_bounds().is_ok());
+        // Single-point ranges are allowed
+        assert!(config_with_bounds(100, 100, 0, 0).validate_launch_bounds().is_ok());
+    }
+
+    #[test]
+    fn launch_bounds_reject_inverted_or_negative_ranges() {
+        assert!(config_with_bounds(1_001, 1_000, 3_600, 86_400).validate_launch_bounds().is_err());
+        assert!(config_with_bounds(10, 1_000, 86_401, 86_400).validate_launch_bounds().is_err());
+        assert!(config_with_bounds(10, 1_000, -1, 86_400).validate_launch_bounds().is_err());
+    }
+
+    #[test]
+    fn launch_params_must_fall_within_bounds() {
+        let config = config_with_bounds(10, 1_000, 3_600, 86_400);
+
+        assert!(config.validate_launch_params(10, 3_600).is_ok());
+        assert!(config.validate_launch_params(1_000, 86_400).is_ok());
+        assert!(config.validate_launch_params(9, 3_600).is_err());
+        assert!(config.validate_launch_params(1_001, 3_600).is_err());
+        assert!(config.validate_launch_params(10, 3_599).is_err());
+        assert!(config.validate_launch_params(10, 86_401).is_err());
+    }
+
+    #[test]
+    fn start_time_may_be_now_but_not_past_or_beyond_max_delay() {
+        let mut config = empty_config();
+        config.max_start_delay = 7 * 86_400;
+        let now = 1_000_000;
+
+        assert!(config.validate_start_time(now - 1, now).is_err());
+        assert!(config.validate_start_time(now, now).is_ok());
+        assert!(config.validate_start_time(now + 7 * 86_400, now).is_ok());
+        assert!(config.validate_start_time(now + 7 * 86_400 + 1, now).is_err());
+
+        // No maximum delay configured
+        config.max_start_delay = 0;
+        assert!(config.validate_start_time(now + 365 * 86_400, now).is_ok());
+    }
+}
@@ -99,8 +99,120 @@ pub struct LaunchPool {
     /// Meteora position NFT account (set after migration)
     pub position_nft_account: Option<Pubkey>,
 
-    /// Reserved space
-    pub reserved: [u64; 4],
+    /// Excess SOL already paid out to participants
+    pub excess_sol_distributed: u64,
+
+    /// Whether the undistributed excess SOL was converted into a SOL dividend campaign
+    pub excess_converted: bool,
+
+    // ===== Near-miss Extension =====
+    /// Minimum share of soft_cap_sol (basis points) raised by end_time to qualify for an extension
+    pub near_miss_bps: u16,
+
+    /// Seconds added to end_time per extension
+    pub near_miss_extension: i64,
+
+    /// Maximum number of automatic extensions (0 = disabled)
+    pub max_extensions: u8,
+
+    /// Number of extensions already applied
+    pub extensions_used: u8,
+
+    // ===== Participation Limits =====
+    /// Maximum participations per user (0 = unlimited)
+    pub max_participations_per_user: u32,
+
+    /// Minimum signed total_points a user must hold to participate (0 = no minimum)
+    pub min_points_to_participate: u64,
+
+    // ===== Fees =====
+    /// Override recipient of the creator's pool fee share (None = creator)
+    pub creator_fee_recipient_override: Option<Pubkey>,
+
+    /// Whether the creator's pool fee share accumulates in program-held escrow
+    pub escrow_creator_fees: bool,
+
+    // ===== Claim Window =====
+    /// Earliest time sale tokens can be claimed (0 = immediately after migration)
+    pub claim_start_time: i64,
+
+    /// Whether excess SOL refunds also wait for claim_start_time
+    pub gate_excess_claims: bool,
+
+    // ===== Creator Bond =====
+    /// SOL bond (lamports) held on this account, refunded on migration or slashed on failure
+    pub creator_bond: u64,
+
+    /// Whether the creator bond has been refunded or slashed
+    pub bond_settled: bool,
+
+    // ===== Excess Settlement =====
+    /// Number of positions whose excess SOL share has been settled
+    pub excess_claim_count: u32,
+
+    /// Whether the rounding dust left after all excess claims was swept to treasury
+    pub excess_dust_swept: bool,
+
+    // ===== Soft Cap =====
+    /// Minimum raise for the launch to succeed (<= target_sol), target_sol stays the liquidity cap
+    pub soft_cap_sol: u64,
+
+    /// Maximum total raise (>= target_sol), contributions are clamped so raised_sol never exceeds it
+    pub hard_cap_sol: u64,
+
+    // ===== Contribution Limits =====
+    /// Minimum total SOL a user must contribute to this launch
+    pub min_contribution_per_user: u64,
+
+    /// Maximum total SOL a user may contribute to this launch
+    pub max_contribution_per_user: u64,
+
+    /// Whether the admin has paused contributions to this pool
+    pub paused: bool,
+
+    /// Merkle root of wallets allowed to participate (None = open to everyone)
+    pub whitelist_root: Option<[u8; 32]>,
+
+    /// Decimals of the launch token mint
+    pub token_decimals: u8,
+
+    // ===== Allocation Split =====
+    /// Share of total_supply (percent) allocated to the creator
+    pub creator_pct: u8,
+
+    /// Share of total_supply (percent) sold to participants
+    pub sale_pct: u8,
+
+    /// Share of total_supply (percent) paired with SOL as liquidity
+    pub liquidity_pct: u8,
+
+    /// Whether participate_with_sol accepts contributions without points
+    pub allow_open_participation: bool,
+
+    // ===== Sale Token Claims =====
+    /// Sale tokens transferred to participants through claim_user_rewards
+    pub total_tokens_claimed: u64,
+
+    /// Whether the unclaimed sale tokens were swept to treasury
+    pub unsold_tokens_swept: bool,
+
+    /// Minimum number of participants for the launch to succeed (0 = no minimum)
+    pub min_participants: u32,
+
+    /// Base fee (basis points) of the Meteora pool created at migration
+    pub meteora_base_fee_bps: u16,
+
+    /// Share of migrated liquidity (basis points) permanently locked, the rest can be locked later
+    pub permanent_lock_bps: u16,
+
+    /// Seconds after end_time a sub-target launch keeps accepting contributions before it can fail
+    pub grace_period: i64,
+
+    /// SOL refunded to participants of a failed launch
+    pub total_refunded: u64,
+
+    /// Whether settle_launch_pool removed this pool from the global config's active count
+    pub settled: bool,
 }
 
 impl LaunchPool {
@@ -133,7 +245,110 @@ impl LaunchPool {
         8 + // index
         33 + // position (Option<Pubkey>)
         33 + // position_nft_account (Option<Pubkey>)
-        8 * 4; // reserved (reduced to 4)
+        8 + // excess_sol_distributed
+        1 + // excess_converted
+        2 + // near_miss_bps
+        8 + // near_miss_extension
+        1 + // max_extensions
+        1 + // extensions_used
+        4 + // max_participations_per_user
+        8 + // min_points_to_participate
+        33 + // creator_fee_recipient_override (Option<Pubkey>)
+        1 + // escrow_creator_fees
+        8 + // claim_start_time
+        1 + // gate_excess_claims
+        8 + // creator_bond
+        1 + // bond_settled
+        4 + // excess_claim_count
+        1 + // excess_dust_swept
+        8 + // soft_cap_sol
+        8 + // hard_cap_sol
+        8 + // min_contribution_per_user
+        8 + // max_contribution_per_user
+        1 + // paused
+        33 + // whitelist_root (Option<[u8; 32]>)
+        1 + // token_decimals
+        1 + // creator_pct
+        1 + // sale_pct
+        1 + // liquidity_pct
+        1 + // allow_open_participation
+        8 + // total_tokens_claimed
+        1 + // unsold_tokens_swept
+        4 + // min_participants
+        2 + // meteora_base_fee_bps
+        2 + // permanent_lock_bps
+        8 + // grace_period
+        8 + // total_refunded (replaces reserved)
+        1; // settled
+
+    /// Size of pools created before the layout outgrew the original reserved space
+    /// Such accounts must go through migrate_launch_pool before any other instruction can load them
+    pub const LEGACY_SIZE: usize = 8 + // discriminator
+        32 * 5 + // creator, token_mint, token_vault, quote_vault, quote_mint
+        1 + // status (enum)
+        1 + // bump
+        8 * 4 + // token allocations
+        8 * 4 + // target_sol, raised_sol, liquidity_sol, excess_sol
+        8 * 3 + // start_time, end_time, finalized_time
+        8 * 2 + // points_per_sol, total_points_consumed
+        4 + // participants_count
+        8 * 4 + // creator lock and claim fields
+        8 + // index
+        33 * 2 + // position, position_nft_account (Option<Pubkey>)
+        8 * 4; // reserved
+
+    /// Default the fields added after the legacy layout to the behavior legacy pools launched with
+    /// Only valid right after migrate_launch_pool zero-extends a legacy account
+    pub fn apply_legacy_defaults(&mut self) {
+        use crate::constants::*;
+
+        // Legacy launches succeeded at target_sol, had no raise cap and used the fixed per-user limits
+        self.soft_cap_sol = self.target_sol;
+        self.hard_cap_sol = u64::MAX;
+        self.min_contribution_per_user = MIN_CONTRIBUTION_PER_USER;
+        self.max_contribution_per_user = MAX_CONTRIBUTION_PER_USER;
+        self.token_decimals = TOKEN_DECIMALS;
+        self.creator_pct = CREATOR_ALLOCATION_PERCENT;
+        self.sale_pct = SALE_ALLOCATION_PERCENT;
+        self.liquidity_pct = LIQUIDITY_ALLOCATION_PERCENT;
+        self.meteora_base_fee_bps = DEFAULT_POOL_FEE_BPS;
+        self.permanent_lock_bps = DEFAULT_PERMANENT_LOCK_BPS;
+
+        // Sale token claims made before migration were never counted, so sweeping could take
+        // tokens that are not unsold; keep legacy migrated pools out of sweep_unsold_tokens
+        self.unsold_tokens_swept = self.status == LaunchStatus::Migrated;
+    }
+
+    /// Check that `key` is the canonical launch pool PDA for this pool's creator and index
+    pub fn verify_canonical_address(&self, key: &Pubkey) -> Result<()> {
+        let expected_key = Pubkey::create_program_address(
+            &[
+                crate::constants::LAUNCH_POOL_SEED,
+                self.creator.as_ref(),
+                &self.index.to_le_bytes(),
+                &[self.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| crate::errors::LaunchpadError::InvalidLaunchPool)?;
+        require_keys_eq!(expected_key, *key, crate::errors::LaunchpadError::InvalidLaunchPool);
+
+        Ok(())
+    }
+
+    /// Load a launch pool passed outside the Accounts struct, such as a remaining account
+    /// Owner and discriminator are checked on deserialization, the address against the canonical PDA
+    pub fn load_canonical<'info>(account_info: &'info AccountInfo<'info>) -> Result<Account<'info, LaunchPool>> {
+        let launch_pool: Account<'info, LaunchPool> = Account::try_from(account_info)?;
+        launch_pool.verify_canonical_address(account_info.key)?;
+
+        Ok(launch_pool)
+    }
+
+    /// Check if the launch is scheduled and waiting for activate_launch
+    pub fn is_initialized(&self) -> bool {
+        self.status == LaunchStatus::Initialized
+    }
 
     /// Check if fundraising is in active status
     pub fn is_active(&self) -> bool {
@@ -145,6 +360,42 @@ impl LaunchPool {
         self.status == LaunchStatus::Success
     }
 
+    /// Check if fundraising has failed
+    pub fn is_failed(&self) -> bool {
+        self.status == LaunchStatus::Failed
+    }
+
+    /// Check if the launch has reached a final state (failed or migrated)
+    pub fn is_terminal(&self) -> bool {
+        self.status == LaunchStatus::Failed || self.status == LaunchStatus::Migrated
+    }
+
+    /// Whether the pool no longer needs the global config: migrated, or failed with every refund paid
+    /// `quote_vault_amount` covers legacy pools whose earlier refunds were not counted in total_refunded
+    pub fn can_settle(&self, quote_vault_amount: Option<u64>) -> bool {
+        match self.status {
+            LaunchStatus::Migrated => true,
+            LaunchStatus::Failed => {
+                self.total_refunded >= self.raised_sol || quote_vault_amount == Some(0)
+            }
+            _ => false,
+        }
+    }
+
+    /// Add a failed launch refund to the refunded total
+    pub fn record_refund(&mut self, amount: u64) -> Result<()> {
+        self.total_refunded = self.total_refunded
+            .checked_add(amount)
+            .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
+
+        Ok(())
+    }
+
+    /// Account receiving the creator's pool fee share
+    pub fn creator_fee_recipient(&self) -> Pubkey {
+        self.creator_fee_recipient_override.unwrap_or(self.creator)
+    }
+
     /// is migrated
     pub fn is_migrated(&self) -> bool {
         self.status == LaunchStatus::Migrated
@@ -157,14 +408,108 @@ impl LaunchPool {
             .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
 
         // Calculate actual SOL for liquidity and excess SOL
+        (self.liquidity_sol, self.excess_sol) = self.expected_raise_split();
+
+        Ok(())
+    }
+
+    /// Whether a wallet may participate, always true when no whitelist is set
+    pub fn is_whitelisted(&self, user: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        match self.whitelist_root {
+            Some(root) => crate::utils::verify_proof(&root, crate::utils::whitelist_leaf(user), proof),
+            None => true,
+        }
+    }
+
+    /// Check a wallet against the whitelist, passing when no whitelist is set
+    pub fn check_whitelist(&self, user: &Pubkey, proof: &[[u8; 32]]) -> Result<()> {
+        require!(
+            self.is_whitelisted(user, proof),
+            crate::errors::LaunchpadError::NotWhitelisted
+        );
+
+        Ok(())
+    }
+
+    /// Check if enough SOL was raised for the launch to succeed
+    pub fn is_soft_cap_reached(&self) -> bool {
+        self.raised_sol >= self.soft_cap_sol
+    }
+
+    /// Ensure a contribution fits under hard_cap_sol
+    pub fn validate_hard_cap(&self, sol_amount: u64) -> Result<()> {
+        require!(
+            self.raised_sol < self.hard_cap_sol,
+            crate::errors::LaunchpadError::HardCapReached
+        );
+        require!(
+            sol_amount <= self.hard_cap_sol - self.raised_sol,
+            crate::errors::LaunchpadError::InvalidContribution
+        );
+
+        Ok(())
+    }
+
+    /// Check if the launch meets every success criterion: soft cap raised by enough participants
+    pub fn meets_success_criteria(&self) -> bool {
+        self.is_soft_cap_reached() && self.participants_count >= self.min_participants
+    }
+
+    /// SOL still needed to reach target_sol
+    /// Contributions past the target are still accepted up to hard_cap_sol and become excess_sol
+    pub fn remaining_capacity_sol(&self) -> u64 {
+        self.target_sol.saturating_sub(self.raised_sol)
+    }
+
+    /// Liquidity/excess split implied by raised_sol and target_sol
+    pub fn expected_raise_split(&self) -> (u64, u64) {
         if self.raised_sol > self.target_sol {
-            self.liquidity_sol = self.target_sol;
-            self.excess_sol = self.raised_sol - self.target_sol;
+            (self.target_sol, self.raised_sol - self.target_sol)
         } else {
-            self.liquidity_sol = self.raised_sol;
-            self.excess_sol = 0;
+            (self.raised_sol, 0)
+        }
+    }
+
+    /// Time after which a launch that has not reached its target can be finalized as failed
+    pub fn failure_deadline(&self) -> i64 {
+        self.end_time.saturating_add(self.grace_period)
+    }
+
+    /// Check if an expired launch narrowly missed its soft cap and can still be extended
+    pub fn can_extend_near_miss(&self, current_time: i64) -> bool {
+        if self.extensions_used >= self.max_extensions
+            || current_time <= self.failure_deadline()
+            || self.is_soft_cap_reached()
+        {
+            return false;
         }
 
+        // raised_sol / soft_cap_sol >= near_miss_bps / 10000
+        (self.raised_sol as u128) * (crate::constants::MAX_BASIS_POINT as u128)
+            >= (self.soft_cap_sol as u128) * (self.near_miss_bps as u128)
+    }
+
+    /// Push end_time out by one near-miss extension
+    pub fn extend_end_time(&mut self) -> Result<()> {
+        self.end_time = self.end_time
+            .checked_add(self.near_miss_extension)
+            .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
+        self.extensions_used = self.extensions_used
+            .checked_add(1)
+            .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
+
+        Ok(())
+    }
+
+    /// Record a participant's excess SOL share as settled
+    pub fn record_excess_distributed(&mut self, amount: u64) -> Result<()> {
+        self.excess_sol_distributed = self.excess_sol_distributed
+            .checked_add(amount)
+            .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
+        self.excess_claim_count = self.excess_claim_count
+            .checked_add(1)
+            .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
+
         Ok(())
     }
 
@@ -234,3 +579,131 @@ impl LaunchPool {
         (lock_end_time, unlock_end_time, claimable_amount, is_locked)
     }
 }
+
+#[cfg(test)]
+impl LaunchPool {
+    /// Zeroed pool in the given status, for unit tests of pool rules
+    pub(crate) fn test_pool(status: LaunchStatus) -> Self {
+        let mut data = vec![0u8; Self::SIZE];
+        data[..8].copy_from_slice(Self::DISCRIMINATOR);
+        let mut pool = Self::try_deserialize(&mut &data[..]).unwrap();
+        pool.status = status;
+        pool
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::Discriminator;
+
+    const STATUS_OFFSET: usize = 8 + 32 * 5;
+    const TARGET_SOL_OFFSET: usize = STATUS_OFFSET + 2 + 8 * 4;
+    const INDEX_OFFSET: usize = TARGET_SOL_OFFSET + 8 * 4 + 8 * 3 + 8 * 2 + 4 + 8 * 4;
+
+    /// Bytes of a pool written under the legacy layout, with an optional Meteora position
+    fn legacy_pool_bytes(status: LaunchStatus, target_sol: u64, position: Option<Pubkey>) -> Vec<u8> {
+        let mut data = vec![0u8; LaunchPool::LEGACY_SIZE];
+        data[..8].copy_from_slice(LaunchPool::DISCRIMINATOR);
+        data[STATUS_OFFSET] = status as u8;
+        data[TARGET_SOL_OFFSET..TARGET_SOL_OFFSET + 8].copy_from_slice(&target_sol.to_le_bytes());
+        data[INDEX_OFFSET..INDEX_OFFSET + 8].copy_from_slice(&42u64.to_le_bytes());
+
+        // Borsh writes None as a single byte, so the tail shifts with the options
+        let mut offset = INDEX_OFFSET + 8;
+        for option in [position, position] {
+            match option {
+                Some(key) => {
+                    data[offset] = 1;
+                    data[offset + 1..offset + 33].copy_from_slice(key.as_ref());
+                    offset += 33;
+                }
+                None => offset += 1,
+            }
+        }
+        data
+    }
+
+    fn migrate(mut data: Vec<u8>) -> LaunchPool {
+        assert!(LaunchPool::try_deserialize(&mut &data[..]).is_err());
+        data.resize(LaunchPool::SIZE, 0);
+        let mut pool = LaunchPool::try_deserialize(&mut &data[..]).unwrap();
+        pool.apply_legacy_defaults();
+        pool
+    }
+
+    #[test]
+    fn migrated_active_legacy_pool_keeps_legacy_rules() {
+        let pool = migrate(legacy_pool_bytes(LaunchStatus::Active, 100, None));
+
+        assert!(pool.is_active());
+        assert_eq!(pool.index, 42);
+        assert_eq!(pool.target_sol, 100);
+        assert_eq!(pool.soft_cap_sol, 100);
+        assert_eq!(pool.hard_cap_sol, u64::MAX);
+        assert_eq!(pool.max_contribution_per_user, crate::constants::MAX_CONTRIBUTION_PER_USER);
+        assert_eq!(pool.token_decimals, crate::constants::TOKEN_DECIMALS);
+        assert!(pool.position.is_none());
+        assert!(!pool.unsold_tokens_swept);
+    }
+
+    #[test]
+    fn migrated_legacy_pool_with_position_is_not_sweepable() {
+        let position = Pubkey::new_unique();
+        let pool = migrate(legacy_pool_bytes(LaunchStatus::Migrated, 100, Some(position)));
+
+        assert!(pool.is_migrated());
+        assert_eq!(pool.position, Some(position));
+        assert_eq!(pool.position_nft_account, Some(position));
+        assert!(pool.unsold_tokens_swept);
+    }
+
+    #[test]
+    fn failed_pool_settles_only_after_every_refund() {
+        let mut pool = migrate(legacy_pool_bytes(LaunchStatus::Failed, 100, None));
+        pool.raised_sol = 30;
+
+        assert!(!pool.can_settle(None));
+        assert!(!pool.can_settle(Some(30)));
+
+        pool.record_refund(20).unwrap();
+        assert!(!pool.can_settle(Some(10)));
+
+        pool.record_refund(10).unwrap();
+        assert!(pool.can_settle(None));
+    }
+
+    #[test]
+    fn legacy_failed_pool_settles_once_quote_vault_is_empty() {
+        let mut pool = migrate(legacy_pool_bytes(LaunchStatus::Failed, 100, None));
+        // Refunds paid before total_refunded existed were never counted
+        pool.raised_sol = 30;
+
+        assert!(pool.can_settle(Some(0)));
+        assert!(!pool.settled);
+    }
+
+    #[test]
+    fn raise_split_sends_only_the_overflow_to_excess() {
+        let mut pool = LaunchPool::test_pool(LaunchStatus::Success);
+        pool.target_sol = 100;
+
+        pool.raised_sol = 80;
+        assert_eq!(pool.expected_raise_split(), (80, 0));
+
+        pool.raised_sol = 100;
+        assert_eq!(pool.expected_raise_split(), (100, 0));
+
+        pool.raised_sol = 135;
+        assert_eq!(pool.expected_raise_split(), (100, 35));
+    }
+
+    #[test]
+    fn only_terminal_pools_settle() {
+        let pool = migrate(legacy_pool_bytes(LaunchStatus::Active, 100, None));
+        assert!(!pool.can_settle(Some(0)));
+
+        let pool = migrate(legacy_pool_bytes(LaunchStatus::Migrated, 100, Some(Pubkey::new_unique())));
+        assert!(pool.can_settle(None));
+    }
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+
+/// Immutable financial record of a launch written once it reaches a terminal state
+#[account]
+pub struct LaunchSummary {
+    /// Launch pool the summary describes
+    pub launch_pool: Pubkey,
+
+    /// Launch token mint
+    pub token_mint: Pubkey,
+
+    /// Launch creator
+    pub creator: Pubkey,
+
+    /// Terminal status (LaunchStatus as u8)
+    pub status: u8,
+
+    // ===== SOL Figures =====
+    /// Target amount
+    pub target_sol: u64,
+
+    /// Total amount raised
+    pub raised_sol: u64,
+
+    /// SOL deposited as liquidity
+    pub liquidity_sol: u64,
+
+    /// Excess SOL above the liquidity deposit
+    pub excess_sol: u64,
+
+    /// Excess SOL refunded or swept at the time of summary
+    pub excess_distributed: u64,
+
+    /// Whether the undistributed excess was converted into dividends
+    pub excess_converted: bool,
+
+    // ===== Token Figures =====
+    /// Tokens deposited as liquidity
+    pub liquidity_allocation: u64,
+
+    /// Tokens sold to participants
+    pub sale_allocation: u64,
+
+    /// Tokens allocated to the creator
+    pub creator_allocation: u64,
+
+    // ===== Participation =====
+    /// Number of participants
+    pub participants_count: u32,
+
+    /// Total points consumed
+    pub total_points_consumed: u64,
+
+    // ===== Time Records =====
+    /// Finalization time of the launch
+    pub finalized_time: i64,
+
+    /// Time the summary was written
+    pub summarized_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Reserved space for future upgrades
+    pub reserved: [u64; 4],
+}
+
+impl LaunchSummary {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // launch_pool
+        32 + // token_mint
+        32 + // creator
+        1 +  // status
+        8 +  // target_sol
+        8 +  // raised_sol
+        8 +  // liquidity_sol
+        8 +  // excess_sol
+        8 +  // excess_distributed
+        1 +  // excess_converted
+        8 +  // liquidity_allocation
+        8 +  // sale_allocation
+        8 +  // creator_allocation
+        4 +  // participants_count
+        8 +  // total_points_consumed
+        8 +  // finalized_time
+        8 +  // summarized_at
+        1 +  // bump
+        8 * 4; // reserved
+
+    pub const SEED: &'static [u8] = b"launch_summary";
+}
@@ -1,13 +1,27 @@
+pub mod dividend_pool;
 pub mod global_config;
 pub mod launch_pool;
+pub mod launch_summary;
+pub mod platform_stats;
+pub mod reward_epoch;
+pub mod staking_counter;
 pub mod staking_position;
+pub mod staking_reward_config;
+pub mod symbol_registry;
 pub mod user_dividend_record;
 pub mod user_point;
 pub mod user_position;
 
+pub use dividend_pool::*;
 pub use global_config::*;
 pub use launch_pool::*;
+pub use launch_summary::*;
+pub use platform_stats::*;
+pub use reward_epoch::*;
+pub use staking_counter::*;
 pub use staking_position::*;
+pub use staking_reward_config::*;
+pub use symbol_registry::*;
 pub use user_dividend_record::*;
 pub use user_point::*;
 pub use user_position::*;
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::errors::LaunchpadError;
+
+/// Platform-wide launch totals, so analytics do not have to fetch every launch pool
+#[account]
+pub struct PlatformStats {
+    /// Number of launches finalized as success or failure
+    pub total_pools: u64,
+
+    /// Total SOL raised by launches that migrated into a Meteora pool
+    pub total_raised_sol: u64,
+
+    /// Number of launches finalized as success
+    pub total_successful: u64,
+
+    /// Number of launches finalized as failure
+    pub total_failed: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Launch pool index a reinitialized global config resumes counting from
+    /// Set by close_config so new launch pool addresses never collide with earlier ones
+    pub next_pool_index: u64,
+
+    /// Reserved space for future upgrades
+    pub reserved: [u64; 3],
+}
+
+impl PlatformStats {
+    pub const SIZE: usize = 8 + // discriminator
+        8 +  // total_pools
+        8 +  // total_raised_sol
+        8 +  // total_successful
+        8 +  // total_failed
+        1 +  // bump
+        8 +  // next_pool_index
+        8 * 3; // reserved
+
+    pub const SEED: &'static [u8] = b"platform_stats";
+
+    /// Count a launch reaching its final success or failure status
+    pub fn record_finalization(&mut self, success: bool) -> Result<()> {
+        self.total_pools = self.total_pools
+            .checked_add(1)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
+        let counter = if success { &mut self.total_successful } else { &mut self.total_failed };
+        *counter = counter
+            .checked_add(1)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Add a migrated launch's raise to the platform total
+    pub fn record_migration(&mut self, raised_sol: u64) -> Result<()> {
+        self.total_raised_sol = self.total_raised_sol
+            .checked_add(raised_sol)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::errors::LaunchpadError;
+
+/// Latest staking snapshot for a token, used as the reward denominator off-chain
+#[account]
+pub struct RewardEpoch {
+    /// Token mint address of the staked token
+    pub token_mint: Pubkey,
+
+    /// Index of the current epoch (starts at 1, 0 = no epoch opened yet)
+    pub epoch_index: u64,
+
+    /// Total amount staked for this token when the epoch was opened
+    pub total_staked: u64,
+
+    /// Timestamp when the epoch was opened
+    pub opened_at: i64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Reserved space for future upgrades
+    pub reserved: [u64; 4],
+}
+
+impl RewardEpoch {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // token_mint
+        8 +  // epoch_index
+        8 +  // total_staked
+        8 +  // opened_at
+        1 +  // bump
+        8 * 4; // reserved
+
+    pub const SEED: &'static [u8] = b"reward_epoch";
+
+    /// Start the next epoch with a fresh snapshot
+    pub fn open_next(
+        &mut self,
+        token_mint: Pubkey,
+        total_staked: u64,
+        current_time: i64,
+        bump: u8,
+    ) -> Result<()> {
+        self.token_mint = token_mint;
+        self.epoch_index = self.epoch_index
+            .checked_add(1)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        self.total_staked = total_staked;
+        self.opened_at = current_time;
+        self.bump = bump;
+        Ok(())
+    }
+}
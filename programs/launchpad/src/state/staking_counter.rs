@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::errors::LaunchpadError;
+
+/// Per user and mint counter handing out staking position indexes
+#[account]
+pub struct StakingCounter {
+    /// User who owns the staking positions
+    pub user: Pubkey,
+
+    /// Token mint address of the staked token
+    pub token_mint: Pubkey,
+
+    /// Index assigned to the next opened position
+    pub next_position_index: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Reserved space for future upgrades
+    pub reserved: [u64; 4],
+}
+
+impl StakingCounter {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // user
+        32 + // token_mint
+        8 +  // next_position_index
+        1 +  // bump
+        8 * 4; // reserved
+
+    pub const SEED: &'static [u8] = b"staking_counter";
+
+    /// Hand out the next position index and advance the counter
+    pub fn next_index(&mut self, user: Pubkey, token_mint: Pubkey, bump: u8) -> Result<u64> {
+        self.user = user;
+        self.token_mint = token_mint;
+        self.bump = bump;
+
+        let index = self.next_position_index;
+        self.next_position_index = index
+            .checked_add(1)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
+        Ok(index)
+    }
+}
@@ -24,8 +24,20 @@ pub struct StakingPosition {
     /// Bump seed for PDA
     pub bump: u8,
 
+    /// Index of this position among the user's positions for the mint
+    pub position_index: u64,
+
+    /// Reward-per-token value up to which this position's rewards are settled
+    pub reward_debt: u128,
+
+    /// Settled rewards not yet claimed
+    pub pending_rewards: u64,
+
+    /// Staked amount enrolled in the reward accumulator's total_staked
+    pub reward_tracked_amount: u64,
+
     /// Reserved space for future upgrades
-    pub reserved: [u64; 8],
+    pub reserved: [u64; 3],
 }
 
 impl StakingPosition {
@@ -37,13 +49,18 @@ impl StakingPosition {
         8 +  // stake_time
         8 +  // unlock_time
         1 +  // bump
-        8 * 8; // reserved
+        8 +  // position_index
+        16 + // reward_debt
+        8 +  // pending_rewards
+        8 +  // reward_tracked_amount
+        8 * 3; // reserved
 
     pub const SEED: &'static [u8] = b"staking_position";
 
     /// Check if the staking position can be unstaked
-    pub fn can_unstake(&self, current_time: i64) -> bool {
-        current_time >= self.unlock_time
+    /// `grace_seconds` tolerates validator clock skew right at the unlock boundary
+    pub fn can_unstake(&self, current_time: i64, grace_seconds: i64) -> bool {
+        current_time.saturating_add(grace_seconds) >= self.unlock_time
     }
 
     /// Initialize staking position
@@ -66,7 +83,38 @@ impl StakingPosition {
             .checked_add(lock_duration)
             .ok_or(LaunchpadError::MathOverflow)?;
         self.bump = bump;
-        self.reserved = [0; 8];
+        self.reserved = [0; 3];
+        Ok(())
+    }
+
+    /// Take over another position's stake, lock schedule and reward accounting under a new owner and index
+    /// Reward accounting moves with the stake, so the accumulator's total_staked is unchanged
+    pub fn move_from(&mut self, source: &StakingPosition, user: Pubkey, position_index: u64, bump: u8) {
+        self.user = user;
+        self.token_mint = source.token_mint;
+        self.staked_amount = source.staked_amount;
+        self.lock_duration = source.lock_duration;
+        self.stake_time = source.stake_time;
+        self.unlock_time = source.unlock_time;
+        self.bump = bump;
+        self.position_index = position_index;
+        self.reward_debt = source.reward_debt;
+        self.pending_rewards = source.pending_rewards;
+        self.reward_tracked_amount = source.reward_tracked_amount;
+        self.reserved = [0; 3];
+    }
+
+    /// Push the unlock time out without restaking, never shortening the lock
+    pub fn extend_lock(&mut self, additional_duration: i64) -> Result<()> {
+        require!(additional_duration > 0, LaunchpadError::InvalidStakeDuration);
+
+        self.lock_duration = self.lock_duration
+            .checked_add(additional_duration)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        self.unlock_time = self.unlock_time
+            .checked_add(additional_duration)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
         Ok(())
     }
 
@@ -94,3 +142,118 @@ impl StakingPosition {
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::StakingCounter;
+
+    fn position_address(user: &Pubkey, mint: &Pubkey, position_index: u64) -> Pubkey {
+        Pubkey::find_program_address(
+            &[StakingPosition::SEED, user.as_ref(), mint.as_ref(), &position_index.to_le_bytes()],
+            &crate::ID,
+        )
+        .0
+    }
+
+    fn empty_counter() -> StakingCounter {
+        StakingCounter {
+            user: Pubkey::default(),
+            token_mint: Pubkey::default(),
+            next_position_index: 0,
+            bump: 0,
+            reserved: [0; 4],
+        }
+    }
+
+    fn open_position(
+        counter: &mut StakingCounter,
+        user: Pubkey,
+        mint: Pubkey,
+        amount: u64,
+        lock_duration: i64,
+        now: i64,
+    ) -> StakingPosition {
+        let position_index = counter.next_index(user, mint, 255).unwrap();
+        let mut position = StakingPosition {
+            user: Pubkey::default(),
+            token_mint: Pubkey::default(),
+            staked_amount: 0,
+            lock_duration: 0,
+            stake_time: 0,
+            unlock_time: 0,
+            bump: 0,
+            position_index: 0,
+            reward_debt: 0,
+            pending_rewards: 0,
+            reward_tracked_amount: 0,
+            reserved: [0; 3],
+        };
+        position.initialize(user, mint, amount, lock_duration, now, 255).unwrap();
+        position.position_index = position_index;
+        position
+    }
+
+    #[test]
+    fn two_positions_unlock_independently() {
+        let (user, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut counter = empty_counter();
+
+        let short = open_position(&mut counter, user, mint, 100, 7 * 86_400, 1_000);
+        let long = open_position(&mut counter, user, mint, 200, 30 * 86_400, 1_000);
+
+        assert_eq!((short.position_index, long.position_index), (0, 1));
+        assert_eq!(counter.next_position_index, 2);
+        assert_ne!(position_address(&user, &mint, 0), position_address(&user, &mint, 1));
+
+        // The short lock can be unstaked while the long one is still locked
+        let after_short_lock = 1_000 + 7 * 86_400;
+        assert!(short.can_unstake(after_short_lock, 0));
+        assert!(!long.can_unstake(after_short_lock, 0));
+        assert_eq!(long.staked_amount, 200);
+        assert!(long.can_unstake(1_000 + 30 * 86_400, 0));
+    }
+
+    #[test]
+    fn unlocks_exactly_at_unlock_time() {
+        let mut counter = empty_counter();
+        let position = open_position(&mut counter, Pubkey::new_unique(), Pubkey::new_unique(), 100, 86_400, 1_000);
+        let unlock_time = position.unlock_time;
+
+        assert!(!position.can_unstake(unlock_time - 1, 0));
+        assert!(position.can_unstake(unlock_time, 0));
+
+        // The grace window moves the boundary earlier by exactly grace_seconds
+        assert!(!position.can_unstake(unlock_time - 31, 30));
+        assert!(position.can_unstake(unlock_time - 30, 30));
+    }
+
+    #[test]
+    fn legacy_address_differs_from_indexed_addresses() {
+        let (user, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let legacy = Pubkey::find_program_address(
+            &[StakingPosition::SEED, user.as_ref(), mint.as_ref()],
+            &crate::ID,
+        )
+        .0;
+
+        assert_ne!(legacy, position_address(&user, &mint, 0));
+    }
+
+    #[test]
+    fn moved_position_keeps_stake_and_schedule() {
+        let (user, mint) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let mut counter = empty_counter();
+        let mut legacy = open_position(&mut counter, user, mint, 500, 86_400, 10);
+        legacy.pending_rewards = 3;
+
+        let mut migrated = legacy.clone();
+        migrated.move_from(&legacy, user, 4, 254);
+
+        assert_eq!(migrated.position_index, 4);
+        assert_eq!(migrated.bump, 254);
+        assert_eq!(migrated.staked_amount, 500);
+        assert_eq!(migrated.unlock_time, legacy.unlock_time);
+        assert_eq!(migrated.pending_rewards, 3);
+    }
+}
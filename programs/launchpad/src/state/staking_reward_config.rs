@@ -0,0 +1,216 @@
+use anchor_lang::prelude::*;
+use crate::constants::REWARD_PER_TOKEN_PRECISION;
+use crate::errors::LaunchpadError;
+use crate::state::StakingPosition;
+
+/// Reward-per-token accumulator streaming treasury-funded rewards to stakers of a token
+#[account]
+pub struct StakingRewardConfig {
+    /// Token mint address of the staked token
+    pub token_mint: Pubkey,
+
+    /// Token mint the rewards are paid in
+    pub reward_mint: Pubkey,
+
+    /// Reward tokens streamed per second across all enrolled stake
+    pub reward_rate: u64,
+
+    /// Accumulated rewards per staked token, scaled by REWARD_PER_TOKEN_PRECISION
+    pub reward_per_token_stored: u128,
+
+    /// Timestamp the accumulator was last brought up to date
+    pub last_update_ts: i64,
+
+    /// Staked amount enrolled in rewards, the accumulator denominator
+    pub total_staked: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Funded rewards not yet streamed, accrual stops once this runs out
+    pub reward_budget: u64,
+
+    /// Reserved space for future upgrades
+    pub reserved: [u64; 3],
+}
+
+impl StakingRewardConfig {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // token_mint
+        32 + // reward_mint
+        8 +  // reward_rate
+        16 + // reward_per_token_stored
+        8 +  // last_update_ts
+        8 +  // total_staked
+        1 +  // bump
+        8 +  // reward_budget
+        8 * 3; // reserved
+
+    pub const SEED: &'static [u8] = b"staking_reward_config";
+
+    pub const VAULT_SEED: &'static [u8] = b"staking_reward_vault";
+
+    /// Add funded rewards to the budget the stream draws from
+    /// Time that passed while the budget was empty is settled first so it does not draw on the new funds
+    pub fn fund(&mut self, amount: u64, current_time: i64) -> Result<()> {
+        self.update_rewards(current_time)?;
+        self.reward_budget = self.reward_budget
+            .checked_add(amount)
+            .ok_or(LaunchpadError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Accrue rewards streamed since the last update into reward_per_token_stored
+    /// Streaming is capped at the funded budget so accrued rewards are always backed by the vault
+    pub fn update_rewards(&mut self, current_time: i64) -> Result<()> {
+        let elapsed = current_time.saturating_sub(self.last_update_ts).max(0) as u128;
+
+        if self.total_staked > 0 && elapsed > 0 {
+            let streamed = elapsed
+                .checked_mul(self.reward_rate as u128)
+                .ok_or(LaunchpadError::MathOverflow)?
+                .min(self.reward_budget as u128);
+            let accrued = streamed
+                .checked_mul(REWARD_PER_TOKEN_PRECISION)
+                .ok_or(LaunchpadError::MathOverflow)?
+                / self.total_staked as u128;
+            self.reward_per_token_stored = self.reward_per_token_stored
+                .checked_add(accrued)
+                .ok_or(LaunchpadError::MathOverflow)?;
+            // streamed <= reward_budget, so this cannot underflow
+            self.reward_budget -= streamed as u64;
+        }
+
+        self.last_update_ts = self.last_update_ts.max(current_time);
+
+        Ok(())
+    }
+
+    /// Settle a position's earnings up to the stored accumulator, then re-enroll its full stake
+    /// Must run after update_rewards
+    pub fn settle_position(&mut self, position: &mut StakingPosition) -> Result<()> {
+        let earned = (position.reward_tracked_amount as u128)
+            .checked_mul(self.reward_per_token_stored.saturating_sub(position.reward_debt))
+            .ok_or(LaunchpadError::MathOverflow)?
+            / REWARD_PER_TOKEN_PRECISION;
+        let earned = u64::try_from(earned).map_err(|_| LaunchpadError::TypeCastFailed)?;
+
+        position.pending_rewards = position.pending_rewards
+            .checked_add(earned)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        position.reward_debt = self.reward_per_token_stored;
+
+        self.total_staked = self.total_staked
+            .checked_sub(position.reward_tracked_amount)
+            .and_then(|total| total.checked_add(position.staked_amount))
+            .ok_or(LaunchpadError::MathOverflow)?;
+        position.reward_tracked_amount = position.staked_amount;
+
+        Ok(())
+    }
+
+    /// Remove a position's stake from the accumulator, e.g. when it is unstaked
+    pub fn withdraw_position(&mut self, position: &mut StakingPosition) -> Result<()> {
+        self.total_staked = self.total_staked
+            .checked_sub(position.reward_tracked_amount)
+            .ok_or(LaunchpadError::MathOverflow)?;
+        position.reward_tracked_amount = 0;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(reward_rate: u64, reward_budget: u64) -> StakingRewardConfig {
+        StakingRewardConfig {
+            token_mint: Pubkey::new_unique(),
+            reward_mint: Pubkey::new_unique(),
+            reward_rate,
+            reward_per_token_stored: 0,
+            last_update_ts: 0,
+            total_staked: 0,
+            bump: 255,
+            reward_budget,
+            reserved: [0; 3],
+        }
+    }
+
+    fn position(staked_amount: u64) -> StakingPosition {
+        StakingPosition {
+            user: Pubkey::new_unique(),
+            token_mint: Pubkey::new_unique(),
+            staked_amount,
+            stake_time: 0,
+            unlock_time: 0,
+            lock_duration: 0,
+            bump: 255,
+            position_index: 0,
+            reward_debt: 0,
+            pending_rewards: 0,
+            reward_tracked_amount: 0,
+            reserved: [0; 3],
+        }
+    }
+
+    fn enroll(config: &mut StakingRewardConfig, position: &mut StakingPosition, time: i64) {
+        config.update_rewards(time).unwrap();
+        config.settle_position(position).unwrap();
+    }
+
+    #[test]
+    fn two_stakers_accrue_in_proportion_to_stake() {
+        let mut config = config(10, u64::MAX);
+        let mut alice = position(100);
+        let mut bob = position(300);
+
+        enroll(&mut config, &mut alice, 0);
+        // Alice earns the whole stream alone for 100 seconds
+        enroll(&mut config, &mut bob, 100);
+        // Then 1/4 of it for the next 100 seconds, Bob 3/4
+        enroll(&mut config, &mut alice, 200);
+        enroll(&mut config, &mut bob, 200);
+
+        assert_eq!(alice.pending_rewards, 1_000 + 250);
+        assert_eq!(bob.pending_rewards, 750);
+        assert_eq!(config.total_staked, 400);
+    }
+
+    #[test]
+    fn accrual_stops_at_funded_budget() {
+        let mut config = config(10, 500);
+        let mut alice = position(100);
+        let mut bob = position(300);
+
+        enroll(&mut config, &mut alice, 0);
+        enroll(&mut config, &mut bob, 0);
+        enroll(&mut config, &mut alice, 1_000);
+        enroll(&mut config, &mut bob, 1_000);
+
+        assert_eq!(alice.pending_rewards, 125);
+        assert_eq!(bob.pending_rewards, 375);
+        assert_eq!(config.reward_budget, 0);
+
+        // Nothing more streams until the budget is topped up
+        enroll(&mut config, &mut alice, 2_000);
+        assert_eq!(alice.pending_rewards, 125);
+
+        config.fund(40, 3_000).unwrap();
+        enroll(&mut config, &mut alice, 3_010);
+        enroll(&mut config, &mut bob, 3_010);
+        assert_eq!(alice.pending_rewards, 135);
+        assert_eq!(bob.pending_rewards, 405);
+    }
+
+    #[test]
+    fn budget_is_not_spent_while_nothing_is_staked() {
+        let mut config = config(10, 500);
+        config.update_rewards(1_000).unwrap();
+
+        assert_eq!(config.reward_budget, 500);
+        assert_eq!(config.reward_per_token_stored, 0);
+    }
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+/// Claims a token symbol for a single launch when symbol uniqueness is enforced
+#[account]
+pub struct SymbolRegistry {
+    /// Hash of the normalized token symbol
+    pub symbol_hash: [u8; 32],
+
+    /// Launch pool that registered the symbol
+    pub launch_pool: Pubkey,
+
+    /// Creator of the launch pool
+    pub creator: Pubkey,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+
+    /// Reserved space for future upgrades
+    pub reserved: [u64; 4],
+}
+
+impl SymbolRegistry {
+    pub const SIZE: usize = 8 + // discriminator
+        32 + // symbol_hash
+        32 + // launch_pool
+        32 + // creator
+        1 +  // bump
+        8 * 4; // reserved
+
+    pub const SEED: &'static [u8] = b"symbol_registry";
+
+    /// Hash a token symbol after trimming whitespace and upper-casing ASCII letters
+    pub fn hash_symbol(symbol: &str) -> [u8; 32] {
+        hash(symbol.trim().to_ascii_uppercase().as_bytes()).to_bytes()
+    }
+}
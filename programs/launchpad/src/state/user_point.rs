@@ -8,13 +8,104 @@ pub struct UserPoint {
     /// Points consumed
     pub points_consumed: u64,
 
+    /// Number of dividend records opened by this user
+    pub dividend_record_count: u32,
+
+    /// Total SOL contributed across all launches
+    pub total_contributed_sol: u64,
+
+    /// Number of points reconciliations applied, bound into each signed reconcile message
+    pub reconcile_nonce: u64,
+
+    /// Number of signed participations accepted, bound into each signed points message
+    pub participation_nonce: u64,
+
     /// Reserved space
-    pub reserved: [u64; 8],
+    pub reserved: [u64; 4],
 }
 
 impl UserPoint {
     pub const SIZE: usize = 8 + // discriminator
         32 + // user
         8 + // points_consumed
-        8 * 8; // reserved
+        4 + // dividend_record_count
+        8 + // total_contributed_sol
+        8 + // reconcile_nonce
+        8 + // participation_nonce
+        8 * 4; // reserved
+
+    /// Check a new contribution against the lifetime cap (0 = unlimited)
+    pub fn validate_lifetime_contribution(&self, sol_amount: u64, lifetime_cap: u64) -> Result<()> {
+        if lifetime_cap == 0 {
+            return Ok(());
+        }
+
+        let total = self.total_contributed_sol
+            .checked_add(sol_amount)
+            .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
+        require!(
+            total <= lifetime_cap,
+            crate::errors::LaunchpadError::LifetimeContributionCapExceeded
+        );
+
+        Ok(())
+    }
+
+    /// Add a contribution to the lifetime total checked by validate_lifetime_contribution
+    pub fn record_contribution(&mut self, sol_amount: u64) -> Result<()> {
+        self.total_contributed_sol = self.total_contributed_sol
+            .checked_add(sol_amount)
+            .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
+
+        Ok(())
+    }
+
+    /// Count a newly opened dividend record against the per-user limit
+    pub fn register_dividend_record(&mut self, max_records: u32) -> Result<()> {
+        require!(
+            self.dividend_record_count < max_records,
+            crate::errors::LaunchpadError::TooManyDividendRecords
+        );
+
+        self.dividend_record_count = self.dividend_record_count
+            .checked_add(1)
+            .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn user_point() -> UserPoint {
+        UserPoint {
+            user: Pubkey::new_unique(),
+            points_consumed: 0,
+            dividend_record_count: 0,
+            total_contributed_sol: 0,
+            reconcile_nonce: 0,
+            participation_nonce: 0,
+            reserved: [0; 4],
+        }
+    }
+
+    #[test]
+    fn lifetime_cap_accumulates_across_launches() {
+        let mut user_point = user_point();
+        let lifetime_cap = 10;
+
+        // Contributions to separate launches share the one per-user total
+        for sol_amount in [4, 3, 3] {
+            user_point.validate_lifetime_contribution(sol_amount, lifetime_cap).unwrap();
+            user_point.record_contribution(sol_amount).unwrap();
+        }
+        assert_eq!(user_point.total_contributed_sol, 10);
+        assert!(user_point.validate_lifetime_contribution(1, lifetime_cap).is_err());
+        assert!(user_point.validate_lifetime_contribution(0, lifetime_cap).is_ok());
+
+        // No cap configured
+        assert!(user_point.validate_lifetime_contribution(u64::MAX - 10, 0).is_ok());
+    }
 }
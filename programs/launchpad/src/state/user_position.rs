@@ -35,8 +35,15 @@ pub struct UserPosition {
     /// Last updated time
     pub last_updated: i64,
 
+    // ===== Carved from reserved space, zero on existing positions =====
+    /// Idempotency key of the last accepted participation (0 = none)
+    pub last_idempotency_key: u64,
+
+    /// Number of times the user participated
+    pub participation_count: u32,
+
     /// Reserved space
-    pub reserved: [u64; 8],
+    pub reserved: [u8; 52],
 }
 
 impl UserPosition {
@@ -51,7 +58,9 @@ impl UserPosition {
         1 + // refunded
         8 + // participated_at
         8 + // last_updated
-        8 * 8; // reserved
+        8 + // last_idempotency_key
+        4 + // participation_count
+        52; // reserved
 
     /// Calculate deserved excess SOL
     pub fn calculate_excess_sol(&self, pool_excess: u64, pool_raised: u64) -> Result<u64> {
@@ -67,7 +76,15 @@ impl UserPosition {
             .checked_div(pool_raised as u128)
             .ok_or(error!(crate::errors::LaunchpadError::MathOverflow))?;
 
-        Ok(user_share as u64)
+        let user_share = u64::try_from(user_share)
+            .map_err(|_| error!(crate::errors::LaunchpadError::MathOverflow))?;
+
+        Ok(user_share)
+    }
+
+    /// Whether nothing is left to claim, so the position can be closed
+    pub fn is_settled(&self, pool_excess_sol: u64) -> bool {
+        self.refunded || (self.tokens_claimed && (self.excess_sol_claimed || pool_excess_sol == 0))
     }
 
     /// Update participation information
@@ -94,3 +111,68 @@ impl UserPosition {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(contributed_sol: u64) -> UserPosition {
+        UserPosition {
+            user: Pubkey::new_unique(),
+            pool: Pubkey::new_unique(),
+            bump: 255,
+            contributed_sol,
+            points_consumed: 0,
+            excess_sol_claimed: false,
+            tokens_claimed: false,
+            refunded: false,
+            participated_at: 0,
+            last_updated: 0,
+            last_idempotency_key: 0,
+            participation_count: 1,
+            reserved: [0; 52],
+        }
+    }
+
+    #[test]
+    fn decodes_positions_written_before_participation_tracking() {
+        // Original layout: every field up to last_updated followed by 64 zeroed reserved bytes
+        let user = Pubkey::new_unique();
+        let pool = Pubkey::new_unique();
+        let mut data = UserPosition::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(user.as_ref());
+        data.extend_from_slice(pool.as_ref());
+        data.push(254);
+        data.extend_from_slice(&5_000u64.to_le_bytes());
+        data.extend_from_slice(&700u64.to_le_bytes());
+        data.extend_from_slice(&[1, 0, 0]);
+        data.extend_from_slice(&100i64.to_le_bytes());
+        data.extend_from_slice(&200i64.to_le_bytes());
+        data.extend_from_slice(&[0; 64]);
+        assert_eq!(data.len(), 172);
+        assert_eq!(UserPosition::SIZE, 172);
+
+        let decoded = UserPosition::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(decoded.user, user);
+        assert_eq!(decoded.pool, pool);
+        assert_eq!(decoded.bump, 254);
+        assert_eq!(decoded.contributed_sol, 5_000);
+        assert_eq!(decoded.points_consumed, 700);
+        assert!(decoded.excess_sol_claimed && !decoded.tokens_claimed && !decoded.refunded);
+        assert_eq!((decoded.participated_at, decoded.last_updated), (100, 200));
+        assert_eq!((decoded.last_idempotency_key, decoded.participation_count), (0, 0));
+    }
+
+    #[test]
+    fn excess_share_at_maximal_inputs_fits() {
+        assert_eq!(position(u64::MAX).calculate_excess_sol(u64::MAX, u64::MAX).unwrap(), u64::MAX);
+        assert_eq!(position(u64::MAX / 2).calculate_excess_sol(u64::MAX, u64::MAX).unwrap(), u64::MAX / 2);
+    }
+
+    #[test]
+    fn excess_share_beyond_u64_errors_instead_of_wrapping() {
+        // Contribution above the pool total would give a share past u64::MAX
+        assert!(position(u64::MAX).calculate_excess_sol(u64::MAX, 1).is_err());
+        assert!(position(2).calculate_excess_sol(u64::MAX, 1).is_err());
+    }
+}
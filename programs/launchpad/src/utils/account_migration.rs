@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::LaunchpadError;
+
+/// Grow a program-owned account created under an older layout to `new_size`, zero-filling the tail
+/// `payer` tops up rent exemption for the extra bytes. Returns the previous data length.
+pub fn grow_program_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_size: usize,
+) -> Result<usize> {
+    require_keys_eq!(*account.owner, crate::ID, LaunchpadError::InvalidStatus);
+
+    let old_size = account.data_len();
+    if old_size >= new_size {
+        return Ok(old_size);
+    }
+
+    let required_lamports = Rent::get()?.minimum_balance(new_size);
+    let top_up = required_lamports.saturating_sub(account.lamports());
+    if top_up > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+
+    account.resize(new_size)?;
+
+    Ok(old_size)
+}
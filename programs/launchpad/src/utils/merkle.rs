@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+/// Leaf of a wallet in a whitelist tree: sha256(wallet)
+pub fn whitelist_leaf(wallet: &Pubkey) -> [u8; 32] {
+    hashv(&[wallet.as_ref()]).to_bytes()
+}
+
+/// Verify a Merkle proof built with sorted sibling pairs: parent = sha256(min(a, b) || max(a, b))
+pub fn verify_proof(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LaunchPool;
+    use anchor_lang::Discriminator;
+
+    fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        if a <= b {
+            hashv(&[&a, &b]).to_bytes()
+        } else {
+            hashv(&[&b, &a]).to_bytes()
+        }
+    }
+
+    /// Four-wallet tree, returning the wallets, the root and each wallet's proof
+    fn tree() -> ([Pubkey; 4], [u8; 32], [Vec<[u8; 32]>; 4]) {
+        let wallets = [0; 4].map(|_| Pubkey::new_unique());
+        let leaves = wallets.map(|wallet| whitelist_leaf(&wallet));
+        let left = parent(leaves[0], leaves[1]);
+        let right = parent(leaves[2], leaves[3]);
+        let root = parent(left, right);
+        let proofs = [
+            vec![leaves[1], right],
+            vec![leaves[0], right],
+            vec![leaves[3], left],
+            vec![leaves[2], left],
+        ];
+        (wallets, root, proofs)
+    }
+
+    #[test]
+    fn wallets_in_the_tree_verify() {
+        let (wallets, root, proofs) = tree();
+        for (wallet, proof) in wallets.iter().zip(proofs.iter()) {
+            assert!(verify_proof(&root, whitelist_leaf(wallet), proof));
+        }
+    }
+
+    #[test]
+    fn wallets_outside_the_tree_do_not_verify() {
+        let (wallets, root, proofs) = tree();
+        let outsider = Pubkey::new_unique();
+
+        assert!(!verify_proof(&root, whitelist_leaf(&outsider), &proofs[0]));
+        // A valid proof only works for the wallet it was built for
+        assert!(!verify_proof(&root, whitelist_leaf(&wallets[0]), &proofs[2]));
+        assert!(!verify_proof(&root, whitelist_leaf(&wallets[0]), &[]));
+    }
+
+    #[test]
+    fn pool_without_root_is_open() {
+        let mut data = vec![0u8; LaunchPool::SIZE];
+        data[..8].copy_from_slice(LaunchPool::DISCRIMINATOR);
+        let mut pool = LaunchPool::try_deserialize(&mut &data[..]).unwrap();
+        let (wallets, root, proofs) = tree();
+        let outsider = Pubkey::new_unique();
+
+        assert!(pool.whitelist_root.is_none());
+        assert!(pool.is_whitelisted(&outsider, &[]));
+        assert!(pool.check_whitelist(&outsider, &[]).is_ok());
+
+        pool.whitelist_root = Some(root);
+        assert!(pool.is_whitelisted(&wallets[1], &proofs[1]));
+        assert!(!pool.is_whitelisted(&outsider, &proofs[1]));
+        assert!(pool.check_whitelist(&outsider, &proofs[1]).is_err());
+    }
+}
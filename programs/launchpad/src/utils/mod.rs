@@ -1,8 +1,12 @@
+pub mod account_migration;
+pub mod merkle;
 pub mod pool_liqudity;
 pub mod signature;
 pub mod token;
 pub mod validation;
 
+pub use account_migration::*;
+pub use merkle::*;
 pub use pool_liqudity::*;
 pub use signature::*;
 pub use token::*;
@@ -55,6 +55,40 @@ fn get_initial_liquidity_from_delta_quote(
     return Ok(liquidity.to::<u128>())
 }
 
+// sqrt(P) = sqrt(quote / base) * 2^64 = sqrt(quote * 2^128 / base)
+pub fn compute_sqrt_price_from_amounts(base_amount: u64, quote_amount: u64) -> Result<u128> {
+    require!(base_amount > 0, LaunchpadError::DivisionByZero);
+
+    let price_x128 = U256::from(quote_amount)
+        .checked_shl(128)
+        .ok_or(LaunchpadError::MathOverflow)?
+        .checked_div(U256::from(base_amount))
+        .ok_or(LaunchpadError::DivisionByZero)?;
+
+    let sqrt_price = isqrt(price_x128);
+
+    Ok(sqrt_price
+        .try_into()
+        .map_err(|_| LaunchpadError::TypeCastFailed)?)
+}
+
+// Integer square root (floor) by Newton's method
+fn isqrt(value: U256) -> U256 {
+    if value < U256::from(2u8) {
+        return value;
+    }
+
+    // Start from a power of two above the root so the iteration decreases monotonically
+    let mut x = U256::from(1u8) << value.bit_len().div_ceil(2);
+    loop {
+        let y = (x + value / x) >> 1;
+        if y >= x {
+            return x;
+        }
+        x = y;
+    }
+}
+
 pub fn get_liquidity_for_adding_liquidity(
     base_amount: u64,
     quote_amount: u64,
@@ -74,3 +108,68 @@ pub fn get_liquidity_for_adding_liquidity(
             .map_err(|_| LaunchpadError::TypeCastFailed)?)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{LIQUIDITY_ALLOCATION_PERCENT, MAX_SQRT_PRICE, MIN_SQRT_PRICE, TOTAL_SUPPLY};
+    use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+
+    /// Floor square root property: root^2 <= value < (root + 1)^2
+    fn assert_floor_sqrt(value: U256, root: U256) {
+        let (value, root) = (U512::from(value), U512::from(root));
+        assert!(root * root <= value);
+        assert!((root + U512::from(1u8)) * (root + U512::from(1u8)) > value);
+    }
+
+    #[test]
+    fn isqrt_is_floor_square_root() {
+        assert_eq!(isqrt(U256::ZERO), U256::ZERO);
+        assert_eq!(isqrt(U256::from(1u8)), U256::from(1u8));
+        assert_eq!(isqrt(U256::from(3u8)), U256::from(1u8));
+        assert_eq!(isqrt(U256::from(4u8)), U256::from(2u8));
+        assert_eq!(isqrt(U256::from(1u128 << 64)), U256::from(1u64 << 32));
+
+        for value in [U256::from(99u8), U256::from(u64::MAX), U256::from(u128::MAX), U256::MAX] {
+            assert_floor_sqrt(value, isqrt(value));
+        }
+    }
+
+    #[test]
+    fn equal_amounts_price_at_one() {
+        assert_eq!(compute_sqrt_price_from_amounts(1_000, 1_000).unwrap(), 1u128 << 64);
+    }
+
+    #[test]
+    fn sqrt_price_follows_raise_size() {
+        let base_amount = TOTAL_SUPPLY / 100 * LIQUIDITY_ALLOCATION_PERCENT as u64;
+        let mut previous = 0;
+
+        for raised_sol in [1, 50, 100, 500, 10_000] {
+            let quote_amount = raised_sol * LAMPORTS_PER_SOL;
+            let sqrt_price = compute_sqrt_price_from_amounts(base_amount, quote_amount).unwrap();
+
+            let price_x128 = (U256::from(quote_amount) << 128) / U256::from(base_amount);
+            assert_floor_sqrt(price_x128, U256::from(sqrt_price));
+            assert!((MIN_SQRT_PRICE..=MAX_SQRT_PRICE).contains(&sqrt_price));
+
+            // A bigger raise against the same liquidity allocation opens at a higher price
+            assert!(sqrt_price > previous);
+            previous = sqrt_price;
+        }
+    }
+
+    #[test]
+    fn quadrupled_raise_doubles_sqrt_price() {
+        let base_amount = TOTAL_SUPPLY / 5;
+        let low = compute_sqrt_price_from_amounts(base_amount, 25 * LAMPORTS_PER_SOL).unwrap();
+        let high = compute_sqrt_price_from_amounts(base_amount, 100 * LAMPORTS_PER_SOL).unwrap();
+
+        assert!(high.abs_diff(low * 2) <= 2);
+    }
+
+    #[test]
+    fn zero_base_amount_is_rejected() {
+        assert!(compute_sqrt_price_from_amounts(0, LAMPORTS_PER_SOL).is_err());
+    }
+}
@@ -6,37 +6,122 @@ use anchor_lang::solana_program::ed25519_program::ID as ED25519_ID;
 use std::convert::TryInto;
 use crate::errors::LaunchpadError;
 
-/// LAUNCHPAD_POINTS_V1:{user}:{points_to_use}:{total_points}:{launch_pool}
+/// Domain prefixes keep signatures for one authorization type from being accepted as another
+pub const POINTS_DOMAIN: &str = "LAUNCHPAD_POINTS_V1";
+pub const DIVIDEND_DOMAIN: &str = "LAUNCHPAD_DIVIDEND_V1";
+pub const SOL_DIVIDEND_DOMAIN: &str = "LAUNCHPAD_SOL_DIVIDEND_V1";
+pub const POINTS_RECONCILE_DOMAIN: &str = "LAUNCHPAD_POINTS_RECONCILE_V1";
+pub const POINTS_BATCH_DOMAIN: &str = "LAUNCHPAD_POINTS_BATCH_V1";
+
+/// {domain}:{payload}
+pub fn format_authorization_message(domain: &str, payload: &str) -> Vec<u8> {
+    format!("{}:{}", domain, payload).into_bytes()
+}
+
+/// {user}:{points_to_use}:{total_points}:{launch_pool}:{nonce}
+pub fn points_payload(
+    user: &Pubkey,
+    points_to_use: u64,
+    total_points: u64,
+    launch_pool: &Pubkey,
+    nonce: u64,
+) -> String {
+    format!("{}:{}:{}:{}:{}", user, points_to_use, total_points, launch_pool, nonce)
+}
+
+/// {user}:{points_to_use}:{total_points}:{nonce}
+/// `points_to_use` is the aggregate spent across every pool in the batch
+pub fn points_batch_payload(
+    user: &Pubkey,
+    points_to_use: u64,
+    total_points: u64,
+    nonce: u64,
+) -> String {
+    format!("{}:{}:{}:{}", user, points_to_use, total_points, nonce)
+}
+
+/// {user}:{token_mint}:{total_dividend_amount}:{expiry_ts}
+pub fn dividend_payload(
+    user: &Pubkey,
+    token_mint: &Pubkey,
+    total_dividend_amount: u64,
+    expiry_ts: i64,
+) -> String {
+    format!("{}:{}:{}:{}", user, token_mint, total_dividend_amount, expiry_ts)
+}
+
+/// {user}:{points_consumed}:{nonce}
+pub fn points_reconcile_payload(
+    user: &Pubkey,
+    points_consumed: u64,
+    nonce: u64,
+) -> String {
+    format!("{}:{}:{}", user, points_consumed, nonce)
+}
+
+/// LAUNCHPAD_POINTS_V1:{user}:{points_to_use}:{total_points}:{launch_pool}:{nonce}
 pub fn format_points_message(
     user: &Pubkey,
     points_to_use: u64,
     total_points: u64,
     launch_pool: &Pubkey,
+    nonce: u64,
 ) -> Vec<u8> {
-    let message_string = format!(
-        "LAUNCHPAD_POINTS_V1:{}:{}:{}:{}",
-        user,
-        points_to_use,
-        total_points,
-        launch_pool,
-    );
-    message_string.into_bytes()
+    format_authorization_message(POINTS_DOMAIN, &points_payload(user, points_to_use, total_points, launch_pool, nonce))
 }
 
-/// LAUNCHPAD_DIVIDEND_V1:{user}:{token_mint}:{total_dividend_amount}
+/// LAUNCHPAD_DIVIDEND_V1:{user}:{token_mint}:{total_dividend_amount}:{expiry_ts}
 pub fn format_dividend_message(
     user: &Pubkey,
     token_mint: &Pubkey,
     total_dividend_amount: u64,
+    expiry_ts: i64,
+) -> Vec<u8> {
+    format_authorization_message(DIVIDEND_DOMAIN, &dividend_payload(user, token_mint, total_dividend_amount, expiry_ts))
+}
+
+/// LAUNCHPAD_SOL_DIVIDEND_V1:{user}:{token_mint}:{total_dividend_amount}:{expiry_ts}
+pub fn format_sol_dividend_message(
+    user: &Pubkey,
+    token_mint: &Pubkey,
+    total_dividend_amount: u64,
+    expiry_ts: i64,
+) -> Vec<u8> {
+    format_authorization_message(SOL_DIVIDEND_DOMAIN, &dividend_payload(user, token_mint, total_dividend_amount, expiry_ts))
+}
+
+/// LAUNCHPAD_POINTS_RECONCILE_V1:{user}:{points_consumed}:{nonce}
+pub fn format_points_reconcile_message(
+    user: &Pubkey,
+    points_consumed: u64,
+    nonce: u64,
+) -> Vec<u8> {
+    format_authorization_message(POINTS_RECONCILE_DOMAIN, &points_reconcile_payload(user, points_consumed, nonce))
+}
+
+/// LAUNCHPAD_POINTS_BATCH_V1:{user}:{points_to_use}:{total_points}:{nonce}
+pub fn format_points_batch_message(
+    user: &Pubkey,
+    points_to_use: u64,
+    total_points: u64,
+    nonce: u64,
 ) -> Vec<u8> {
-    let message_string = format!(
-        "LAUNCHPAD_DIVIDEND_V1:{}:{}:{}",
-        user,
-        token_mint,
-        total_dividend_amount,
-    );
-    message_string.into_bytes()
+    format_authorization_message(POINTS_BATCH_DOMAIN, &points_batch_payload(user, points_to_use, total_points, nonce))
+}
+
+/// Verify that `ix` is an Ed25519Program instruction in which `signer` signed `{domain}:{payload}`
+/// Every off-chain authorization goes through here
+pub fn verify_authorization(
+    ix: &Instruction,
+    signer: &Pubkey,
+    domain: &str,
+    payload: &str,
+    signature: &[u8; 64],
+) -> Result<()> {
+    let message = format_authorization_message(domain, payload);
+    verify_ed25519_ix(ix, &signer.to_bytes(), &message, signature)
 }
+
 /// Verify Ed25519Program instruction fields
 pub fn verify_ed25519_ix(ix: &Instruction, pubkey: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
     if  ix.program_id       != ED25519_ID                   ||  // The program id we expect
@@ -53,6 +138,14 @@ pub fn verify_ed25519_ix(ix: &Instruction, pubkey: &[u8], msg: &[u8], sig: &[u8]
 
 /// Verify serialized Ed25519Program instruction data
 pub fn check_ed25519_data(data: &[u8], pubkey: &[u8], msg: &[u8], sig: &[u8]) -> Result<()> {
+    // Reject truncated or mis-sized data before slicing into it
+    if  pubkey.len()    != 32                                   ||
+        sig.len()       != 64                                   ||
+        data.len()      != (16 + 64 + 32 + msg.len())
+    {
+        return Err(LaunchpadError::InvalidSignature.into());
+    }
+
     // According to this layout used by the Ed25519Program
     // https://github.com/solana-labs/solana-web3.js/blob/master/src/ed25519-program.ts#L33
 
@@ -78,7 +171,8 @@ pub fn check_ed25519_data(data: &[u8], pubkey: &[u8], msg: &[u8], sig: &[u8]) ->
     let exp_signature_offset:       u16 = exp_public_key_offset + pubkey.len() as u16;
     let exp_message_data_offset:    u16 = exp_signature_offset + sig.len() as u16;
     let exp_num_signatures:          u8 = 1;
-    let exp_message_data_size:      u16 = msg.len().try_into().unwrap();
+    let exp_message_data_size:      u16 = msg.len().try_into()
+        .map_err(|_| LaunchpadError::InvalidSignature)?;
 
     // Header and Arg Checks
 
@@ -106,3 +200,119 @@ pub fn check_ed25519_data(data: &[u8], pubkey: &[u8], msg: &[u8], sig: &[u8]) ->
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNATURE: [u8; 64] = [7; 64];
+
+    /// Ed25519Program instruction data in the single-signature, inline layout the program expects
+    fn ed25519_data(pubkey: &[u8; 32], msg: &[u8], sig: &[u8; 64]) -> Vec<u8> {
+        let mut data = vec![1u8, 0];
+        for field in [
+            48u16,                 // signature_offset
+            u16::MAX,              // signature_instruction_index
+            16,                    // public_key_offset
+            u16::MAX,              // public_key_instruction_index
+            112,                   // message_data_offset
+            msg.len() as u16,      // message_data_size
+            u16::MAX,              // message_instruction_index
+        ] {
+            data.extend_from_slice(&field.to_le_bytes());
+        }
+        data.extend_from_slice(pubkey);
+        data.extend_from_slice(sig);
+        data.extend_from_slice(msg);
+        data
+    }
+
+    fn ed25519_ix(signer: &Pubkey, msg: &[u8], sig: &[u8; 64]) -> Instruction {
+        Instruction {
+            program_id: ED25519_ID,
+            accounts: vec![],
+            data: ed25519_data(&signer.to_bytes(), msg, sig),
+        }
+    }
+
+    fn payload() -> String {
+        points_payload(&Pubkey::new_unique(), 100, 1_000, &Pubkey::new_unique(), 1)
+    }
+
+    #[test]
+    fn matching_authorization_verifies() {
+        let signer = Pubkey::new_unique();
+        let payload = payload();
+        let ix = ed25519_ix(&signer, &format_authorization_message(POINTS_DOMAIN, &payload), &SIGNATURE);
+
+        assert!(verify_authorization(&ix, &signer, POINTS_DOMAIN, &payload, &SIGNATURE).is_ok());
+    }
+
+    #[test]
+    fn wrong_signer_is_rejected() {
+        let payload = payload();
+        let ix = ed25519_ix(&Pubkey::new_unique(), &format_authorization_message(POINTS_DOMAIN, &payload), &SIGNATURE);
+
+        assert!(verify_authorization(&ix, &Pubkey::new_unique(), POINTS_DOMAIN, &payload, &SIGNATURE).is_err());
+    }
+
+    #[test]
+    fn wrong_message_is_rejected() {
+        let signer = Pubkey::new_unique();
+        let payload = payload();
+        let ix = ed25519_ix(&signer, &format_authorization_message(POINTS_DOMAIN, &payload), &SIGNATURE);
+
+        // Same payload under another domain, and another payload of the same length
+        assert!(verify_authorization(&ix, &signer, DIVIDEND_DOMAIN, &payload, &SIGNATURE).is_err());
+        let other = payload.replace(":100:", ":900:");
+        assert!(verify_authorization(&ix, &signer, POINTS_DOMAIN, &other, &SIGNATURE).is_err());
+        // Signature bytes must match too
+        assert!(verify_authorization(&ix, &signer, POINTS_DOMAIN, &payload, &[8; 64]).is_err());
+    }
+
+    #[test]
+    fn offsets_must_point_inside_the_instruction() {
+        let signer = Pubkey::new_unique();
+        let msg = format_authorization_message(POINTS_DOMAIN, &payload());
+        let valid = ed25519_data(&signer.to_bytes(), &msg, &SIGNATURE);
+        assert!(check_ed25519_data(&valid, signer.as_ref(), &msg, &SIGNATURE).is_ok());
+
+        // Each header field, including the instruction indexes that could point at another instruction
+        for offset in (2..16).step_by(2) {
+            let mut data = valid.clone();
+            data[offset] ^= 1;
+            assert!(check_ed25519_data(&data, signer.as_ref(), &msg, &SIGNATURE).is_err());
+        }
+
+        let mut data = valid.clone();
+        data[0] = 2;
+        assert!(check_ed25519_data(&data, signer.as_ref(), &msg, &SIGNATURE).is_err());
+    }
+
+    #[test]
+    fn truncated_data_is_rejected() {
+        let signer = Pubkey::new_unique();
+        let msg = format_authorization_message(POINTS_DOMAIN, &payload());
+        let data = ed25519_data(&signer.to_bytes(), &msg, &SIGNATURE);
+
+        for len in [0, 1, 15, 16, 48, 112, data.len() - 1] {
+            assert!(check_ed25519_data(&data[..len], signer.as_ref(), &msg, &SIGNATURE).is_err());
+        }
+        assert!(check_ed25519_data(&data, &signer.as_ref()[..31], &msg, &SIGNATURE).is_err());
+        assert!(check_ed25519_data(&data, signer.as_ref(), &msg, &SIGNATURE[..63]).is_err());
+
+        let mut ix = ed25519_ix(&signer, &msg, &SIGNATURE);
+        ix.data.pop();
+        assert!(verify_ed25519_ix(&ix, signer.as_ref(), &msg, &SIGNATURE).is_err());
+    }
+
+    #[test]
+    fn other_programs_are_rejected() {
+        let signer = Pubkey::new_unique();
+        let msg = format_authorization_message(POINTS_DOMAIN, &payload());
+        let mut ix = ed25519_ix(&signer, &msg, &SIGNATURE);
+        ix.program_id = crate::ID;
+
+        assert!(verify_ed25519_ix(&ix, signer.as_ref(), &msg, &SIGNATURE).is_err());
+    }
+}
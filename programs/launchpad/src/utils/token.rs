@@ -1,26 +1,41 @@
 use anchor_lang::prelude::*;
+use anchor_spl::metadata::{create_metadata_accounts_v3, CreateMetadataAccountsV3};
+use anchor_spl::token_interface;
+use mpl_token_metadata::types::DataV2;
 
+use crate::constants::{MAX_TOKEN_DECIMALS, TOTAL_SUPPLY_TOKENS};
 use crate::errors::LaunchpadError;
+use crate::events::CreatorBondSettled;
+use crate::state::LaunchPool;
 
-/// Calculate token allocations
-pub fn calculate_token_allocations(total_supply: u64) -> Result<(u64, u64, u64)> {
-    let creator_allocation = total_supply
-        .checked_mul(crate::constants::CREATOR_ALLOCATION_PERCENT as u64)
-        .ok_or(LaunchpadError::MathOverflow)?
-        .checked_div(100)
-        .ok_or(LaunchpadError::DivisionByZero)?;
+/// Calculate token allocations from the launch's creator/sale/liquidity percentages, which must sum to 100
+/// Sale and liquidity must both be non-empty, or participants pay for nothing or the pool can never migrate
+pub fn calculate_token_allocations(
+    total_supply: u64,
+    creator_pct: u8,
+    sale_pct: u8,
+    liquidity_pct: u8,
+) -> Result<(u64, u64, u64)> {
+    require!(
+        creator_pct as u16 + sale_pct as u16 + liquidity_pct as u16 == 100,
+        LaunchpadError::InvalidTokenAllocation
+    );
+    require!(sale_pct > 0 && liquidity_pct > 0, LaunchpadError::InvalidTokenAllocation);
 
-    let sale_allocation = total_supply
-        .checked_mul(crate::constants::SALE_ALLOCATION_PERCENT as u64)
-        .ok_or(LaunchpadError::MathOverflow)?
-        .checked_div(100)
-        .ok_or(LaunchpadError::DivisionByZero)?;
+    // Widen before multiplying, a 9-decimal default supply times a percent overflows u64
+    let percent_of_supply = |pct: u8| -> Result<u64> {
+        let amount = (total_supply as u128) * (pct as u128) / 100;
+        u64::try_from(amount).map_err(|_| error!(LaunchpadError::MathOverflow))
+    };
+    let creator_allocation = percent_of_supply(creator_pct)?;
+    let sale_allocation = percent_of_supply(sale_pct)?;
 
+    // Liquidity takes the rounding remainder so the split always sums to any chosen supply
     let liquidity_allocation = total_supply
-        .checked_mul(crate::constants::LIQUIDITY_ALLOCATION_PERCENT as u64)
+        .checked_sub(creator_allocation)
         .ok_or(LaunchpadError::MathOverflow)?
-        .checked_div(100)
-        .ok_or(LaunchpadError::DivisionByZero)?;
+        .checked_sub(sale_allocation)
+        .ok_or(LaunchpadError::MathOverflow)?;
 
     // Verify total
     let total = creator_allocation
@@ -33,6 +48,172 @@ pub fn calculate_token_allocations(total_supply: u64) -> Result<(u64, u64, u64)>
         total == total_supply,
         LaunchpadError::InvalidTokenAllocation
     );
+    // A supply too small to round to a non-empty sale and liquidity share is rejected as well
+    require!(
+        sale_allocation > 0 && liquidity_allocation > 0,
+        LaunchpadError::InvalidTokenAllocation
+    );
 
     Ok((creator_allocation, sale_allocation, liquidity_allocation))
 }
+
+/// Supply minted into the launch vault, the custom supply or 1 billion whole tokens at the chosen decimals
+pub fn launch_total_supply(decimals: u8, total_supply: Option<u64>) -> Result<u64> {
+    require!(decimals <= MAX_TOKEN_DECIMALS, LaunchpadError::InvalidTokenAllocation);
+    let total_supply = match total_supply {
+        Some(total_supply) => total_supply,
+        None => TOTAL_SUPPLY_TOKENS
+            .checked_mul(10u64.pow(decimals as u32))
+            .ok_or(LaunchpadError::MathOverflow)?,
+    };
+    require!(total_supply > 0, LaunchpadError::InvalidTokenAllocation);
+
+    Ok(total_supply)
+}
+
+/// Create immutable Metaplex metadata for a launch mint, signed by the launch pool as mint and update authority
+pub fn create_launch_token_metadata<'info>(
+    metadata_program: AccountInfo<'info>,
+    accounts: CreateMetadataAccountsV3<'info>,
+    name: String,
+    symbol: String,
+    uri: String,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let data = DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+
+    create_metadata_accounts_v3(
+        CpiContext::new_with_signer(metadata_program, accounts, signer_seeds),
+        data,
+        false,  // is_mutable
+        true,  // update_authority_is_signer
+        None,  // collection_details
+    )
+}
+
+/// Permanently revoke one of the launch pool's authorities over the launch mint
+pub fn revoke_launch_mint_authority<'info>(
+    token_program: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    launch_pool: AccountInfo<'info>,
+    authority_type: token_interface::spl_token_2022::instruction::AuthorityType,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    token_interface::set_authority(
+        CpiContext::new_with_signer(
+            token_program,
+            token_interface::SetAuthority {
+                current_authority: launch_pool,
+                account_or_mint: mint,
+            },
+            signer_seeds,
+        ),
+        authority_type,
+        None,
+    )
+}
+
+/// Pay out the creator bond held on the launch pool account to `recipient`
+/// Returns the amount moved, 0 if there is no bond or it was already settled
+pub fn settle_creator_bond<'info>(
+    launch_pool: &mut Account<'info, LaunchPool>,
+    recipient: &AccountInfo<'info>,
+    slashed: bool,
+) -> Result<u64> {
+    let amount = launch_pool.creator_bond;
+    if amount == 0 || launch_pool.bond_settled {
+        return Ok(0);
+    }
+
+    // The launch pool is owned by this program, so lamports can be debited directly
+    let pool_info = launch_pool.to_account_info();
+    let pool_lamports = pool_info.lamports()
+        .checked_sub(amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    let recipient_lamports = recipient.lamports()
+        .checked_add(amount)
+        .ok_or(LaunchpadError::MathOverflow)?;
+    **pool_info.try_borrow_mut_lamports()? = pool_lamports;
+    **recipient.try_borrow_mut_lamports()? = recipient_lamports;
+
+    launch_pool.bond_settled = true;
+
+    emit!(CreatorBondSettled {
+        pool: launch_pool.key(),
+        creator: launch_pool.creator,
+        recipient: recipient.key(),
+        amount,
+        slashed,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    msg!("Creator bond of {} lamports {}", amount, if slashed { "slashed" } else { "refunded" });
+
+    Ok(amount)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{TOKEN_DECIMALS, TOTAL_SUPPLY};
+
+    #[test]
+    fn custom_split_allocates_by_percent() {
+        let (creator_allocation, sale_allocation, liquidity_allocation) =
+            calculate_token_allocations(TOTAL_SUPPLY, 20, 60, 20).unwrap();
+        assert_eq!(creator_allocation, TOTAL_SUPPLY / 5);
+        assert_eq!(sale_allocation, TOTAL_SUPPLY / 5 * 3);
+        assert_eq!(liquidity_allocation, TOTAL_SUPPLY / 5);
+    }
+
+    #[test]
+    fn rejects_invalid_splits() {
+        // Percentages not summing to 100
+        assert!(calculate_token_allocations(TOTAL_SUPPLY, 30, 50, 30).is_err());
+        assert!(calculate_token_allocations(TOTAL_SUPPLY, 10, 50, 20).is_err());
+        // Nothing sold, or nothing left to pair as liquidity
+        assert!(calculate_token_allocations(TOTAL_SUPPLY, 50, 0, 50).is_err());
+        assert!(calculate_token_allocations(TOTAL_SUPPLY, 30, 70, 0).is_err());
+        // Supply too small for the sale share to survive rounding
+        assert!(calculate_token_allocations(1, 0, 99, 1).is_err());
+    }
+
+    #[test]
+    fn vault_mint_covers_every_allocation() {
+        // initialize_launch mints launch_total_supply into the vault, whatever the token program
+        assert_eq!(launch_total_supply(TOKEN_DECIMALS, None).unwrap(), TOTAL_SUPPLY);
+        for (decimals, custom_supply) in [(6, None), (0, None), (6, Some(1_234_567_891)), (2, Some(997))] {
+            let minted = launch_total_supply(decimals, custom_supply).unwrap();
+            let (creator_allocation, sale_allocation, liquidity_allocation) =
+                calculate_token_allocations(minted, 30, 50, 20).unwrap();
+            assert_eq!(creator_allocation + sale_allocation + liquidity_allocation, minted);
+        }
+    }
+
+    #[test]
+    fn nine_decimal_supply_splits_without_rounding_loss() {
+        let total_supply = launch_total_supply(9, None).unwrap();
+        assert_eq!(total_supply, 1_000_000_000 * 1_000_000_000);
+
+        let (creator_allocation, sale_allocation, liquidity_allocation) =
+            calculate_token_allocations(total_supply, 30, 50, 20).unwrap();
+        assert_eq!(creator_allocation, total_supply / 10 * 3);
+        assert_eq!(sale_allocation, total_supply / 2);
+        assert_eq!(liquidity_allocation, total_supply / 5);
+        assert_eq!(creator_allocation + sale_allocation + liquidity_allocation, total_supply);
+    }
+
+    #[test]
+    fn rejects_unsupported_supply() {
+        assert!(launch_total_supply(10, None).is_err());
+        assert!(launch_total_supply(6, Some(0)).is_err());
+    }
+}
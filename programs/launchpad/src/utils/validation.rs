@@ -5,6 +5,7 @@ use crate::errors::LaunchpadError;
 use crate::state::{LaunchPool, LaunchStatus};
 
 /// Validate if within time window
+/// A launch below its target keeps accepting contributions through its grace period
 pub fn check_time_window(pool: &LaunchPool, current_time: i64) -> Result<()> {
     msg!("currrrrr {}", current_time);
     require!(
@@ -12,8 +13,9 @@ pub fn check_time_window(pool: &LaunchPool, current_time: i64) -> Result<()> {
         LaunchpadError::NotStarted
     );
 
+    let in_grace_period = pool.raised_sol < pool.target_sol && current_time <= pool.failure_deadline();
     require!(
-        current_time <= pool.end_time,
+        current_time <= pool.end_time || in_grace_period,
         LaunchpadError::TimeWindowExpired
     );
 
@@ -27,35 +29,66 @@ pub fn check_can_finalize(pool: &LaunchPool, current_time: i64) -> Result<()> {
         LaunchpadError::LaunchNotActive
     );
 
-    // Must wait until time window ends or target is reached
-    let time_ended = current_time > pool.end_time;
-    let target_reached = pool.raised_sol >= pool.target_sol;
+    // Single-shot guard that holds even if the status check above is ever relaxed
+    require!(pool.finalized_time == 0, LaunchpadError::AlreadyFinalized);
+
+    // Must wait until the grace period after the time window ends, or until the launch has succeeded
+    // A pool at target but short of min_participants can still gain participants, so it waits
+    let time_ended = current_time > pool.failure_deadline();
+    let succeeded_early = pool.raised_sol >= pool.target_sol && pool.meets_success_criteria();
 
     require!(
-        time_ended || target_reached,
+        time_ended || succeeded_early,
         LaunchpadError::TooEarlyToFinalize
     );
 
     Ok(())
 }
 
-/// Validate contribution amount
+/// Validate contribution amount against the launch's per-user limits
 pub fn validate_contribution_amount(
     amount: u64,
     user_current: u64,
+    min_contribution: u64,
+    max_contribution: u64,
 ) -> Result<()> {
     let total_contribution = user_current
         .checked_add(amount)
         .ok_or(LaunchpadError::MathOverflow)?;
 
     require!(
-        total_contribution >= crate::constants::MIN_CONTRIBUTION_PER_USER && total_contribution <= crate::constants::MAX_CONTRIBUTION_PER_USER,
+        total_contribution >= min_contribution && total_contribution <= max_contribution,
         LaunchpadError::InvalidContribution
     );
 
     Ok(())
 }
 
+/// Clamp a points-derived allowance to the user's remaining per-user headroom
+/// Returns the accepted SOL and the points charged for it (rounded up, never above the requested points)
+pub fn clamp_to_contribution_headroom(
+    sol_allowance: u64,
+    points_to_use: u64,
+    user_current: u64,
+    max_contribution: u64,
+    points_per_sol: u64,
+) -> Result<(u64, u64)> {
+    let headroom = max_contribution.saturating_sub(user_current);
+    if sol_allowance <= headroom {
+        return Ok((sol_allowance, points_to_use));
+    }
+
+    let points_charged = (headroom as u128)
+        .checked_mul(points_per_sol as u128)
+        .ok_or(LaunchpadError::MathOverflow)?
+        .div_ceil(LAMPORTS_PER_SOL as u128);
+    let points_charged = u64::try_from(points_charged)
+        .map_err(|_| LaunchpadError::TypeCastFailed)?
+        .min(points_to_use);
+
+    Ok((headroom, points_charged))
+}
+
 /// Validate points amount
 pub fn validate_points_amount(
     points_to_use: u64,
@@ -93,3 +126,64 @@ pub fn calculate_sol_allowance(points: u64, points_per_sol: u64) -> Result<u64>
 
     Ok(sol_amount)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Active pool at its 100 lamport target, ending at t = 1_000
+    fn pool_at_target(participants_count: u32, min_participants: u32) -> LaunchPool {
+        let mut pool = LaunchPool::test_pool(LaunchStatus::Active);
+        pool.target_sol = 100;
+        pool.soft_cap_sol = 100;
+        pool.raised_sol = 100;
+        pool.end_time = 1_000;
+        pool.participants_count = participants_count;
+        pool.min_participants = min_participants;
+        pool
+    }
+
+    #[test]
+    fn target_reached_with_enough_participants_finalizes_early() {
+        assert!(check_can_finalize(&pool_at_target(5, 5), 500).is_ok());
+    }
+
+    #[test]
+    fn target_reached_with_too_few_participants_waits_for_deadline() {
+        let pool = pool_at_target(4, 5);
+        assert!(check_can_finalize(&pool, 500).is_err());
+        assert!(check_can_finalize(&pool, 1_000).is_err());
+        assert!(check_can_finalize(&pool, 1_001).is_ok());
+    }
+
+    /// Active pool below its target, ending at t = 1_000 with a 300 second grace period
+    fn pool_in_grace() -> LaunchPool {
+        let mut pool = pool_at_target(0, 0);
+        pool.raised_sol = 60;
+        pool.grace_period = 300;
+        pool
+    }
+
+    #[test]
+    fn contributions_below_target_are_accepted_through_grace_period() {
+        let mut pool = pool_in_grace();
+        assert_eq!(pool.failure_deadline(), 1_300);
+
+        assert!(check_time_window(&pool, 1_001).is_ok());
+        assert!(check_time_window(&pool, 1_300).is_ok());
+        assert!(check_time_window(&pool, 1_301).is_err());
+
+        // Once the target is reached the window closes at end_time
+        pool.raised_sol = 100;
+        assert!(check_time_window(&pool, 1_001).is_err());
+    }
+
+    #[test]
+    fn finalize_waits_for_failure_deadline() {
+        let pool = pool_in_grace();
+
+        assert!(check_can_finalize(&pool, 1_001).is_err());
+        assert!(check_can_finalize(&pool, 1_300).is_err());
+        assert!(check_can_finalize(&pool, 1_301).is_ok());
+    }
+}